@@ -0,0 +1,122 @@
+use mongodb::{Client as MongoClient, Database};
+use rizky_pratama_tan_api::client::models::{Client, ClientFile, ClientSecurity};
+use rizky_pratama_tan_api::client::repositories::{create_index, find};
+use rizky_pratama_tan_api::client::services::{create_client, delete_client, import_client, rotate_client, suspend_client};
+use rizky_pratama_tan_api::core::authentication::enums::{Access, Scheme};
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::services::initialize_env;
+use std::env;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+  client create <key> [--ip <ip,cidr>] [--deny <ip,cidr>] [--access <private|public>] [--scheme <rsa|hmac>] [--timestamp] [--size <bits>]
+  client rotate <key> [--size <bits>]
+  client suspend <key>
+  client delete <key>
+  client list
+  client import [directory]";
+
+#[tokio::main]
+async fn main() -> ExitCode {
+	initialize_env();
+
+	let args: Vec<String> = env::args().skip(1).collect();
+	let command: &str = args.first().map(String::as_str).unwrap_or_default();
+	let key: String = args.get(1).cloned().unwrap_or_default();
+
+	let config: AppConfig = match AppConfig::load() {
+		Ok(config) => config,
+		Err(errors) => {
+			for err in errors {
+				eprintln!("{}", err);
+			}
+
+			return ExitCode::FAILURE;
+		}
+	};
+	AppConfig::initialize(&config);
+
+	let database: Database = match MongoClient::with_uri_str(&config.database.connection_string).await {
+		Ok(client) => client.database(&config.database.name),
+		Err(err) => {
+			eprintln!("{:?}", err);
+
+			return ExitCode::FAILURE;
+		}
+	};
+
+	create_index(&database).await;
+
+	let size: usize = get_option(&args, "--size").and_then(|size| size.parse().ok()).unwrap_or(2048);
+
+	let result: Result<(), String> = match command {
+		"create" if !key.is_empty() => {
+			let data: ClientFile = ClientFile {
+				deny: get_option(&args, "--deny").map(|deny| deny.split(',').map(String::from).collect()).unwrap_or_default(),
+				ip: get_option(&args, "--ip").unwrap_or("*".to_string()).split(',').map(String::from).collect(),
+				key,
+				security: ClientSecurity {
+					access: match get_option(&args, "--access").unwrap_or_default().to_lowercase().as_str() {
+						"private" => Access::Private,
+						_ => Access::Public,
+					},
+					scheme: match get_option(&args, "--scheme").unwrap_or_default().to_lowercase().as_str() {
+						"hmac" => Scheme::Hmac,
+						_ => Scheme::Rsa,
+					},
+					timestamp: args.contains(&"--timestamp".to_string()),
+				},
+			};
+
+			create_client(&database, &data, size, None).await.map(print_client).map_err(|err| err.to_string())
+		}
+		"rotate" if !key.is_empty() => rotate_client(&database, &key, size, None).await.map(print_client).map_err(|err| err.to_string()),
+		"suspend" if !key.is_empty() => suspend_client(&database, &key, None).await.map(print_client).map_err(|err| err.to_string()),
+		"delete" if !key.is_empty() => delete_client(&database, &key).await.map_err(|err| err.to_string()),
+		"list" => {
+			for client in find(&database).await.unwrap_or_default() {
+				println!("{}\t{:?}\t{:?}\t{}", client.key, client.status, client.security.access, client.ip.join(","));
+			}
+
+			Ok(())
+		}
+		"import" => {
+			let directory: String = args.get(1).cloned().unwrap_or(config.client.directory.clone());
+
+			for (key, result) in import_client(&database, &directory).await {
+				match result {
+					Ok(_) => println!("{}\timported", key),
+					Err(err) => println!("{}\t{}", key, err),
+				}
+			}
+
+			Ok(())
+		}
+		_ => Err(USAGE.to_string()),
+	};
+
+	match result {
+		Ok(_) => ExitCode::SUCCESS,
+		Err(err) => {
+			eprintln!("{}", err);
+
+			ExitCode::FAILURE
+		}
+	}
+}
+
+fn get_option(args: &[String], name: &str) -> Option<String> {
+	args.iter().position(|arg| arg == name).and_then(|index| args.get(index + 1)).cloned()
+}
+
+fn print_client(client: Client) {
+	println!(
+		"Key : {}\nStatus : {:?}\nIP : {}\nScheme : {:?}\nSecret : {}\n\n{}",
+		client.key,
+		client.status,
+		client.ip.join(","),
+		client.security.scheme,
+		client.secret,
+		client.rsa.public
+	);
+}
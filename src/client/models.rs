@@ -0,0 +1,87 @@
+use crate::core::authentication::enums::{Access, Scheme};
+use crate::core::base::enums::Status;
+use crate::core::database::models::Timestamp;
+use bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Client {
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	#[serde(default)]
+	pub deny: Vec<String>,
+	pub ip: Vec<String>,
+	pub key: String,
+	pub rsa: ClientRsa,
+	#[serde(default)]
+	pub secret: String,
+	pub security: ClientSecurity,
+	pub status: Status,
+	pub created: Timestamp,
+	pub modified: Timestamp,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientData {
+	pub deny: Vec<String>,
+	pub ip: Vec<String>,
+	pub key: String,
+	pub public_key: String,
+	pub secret: Option<String>,
+	pub security: ClientSecurity,
+	pub status: Status,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientFile {
+	#[serde(default)]
+	pub deny: Vec<String>,
+	pub ip: Vec<String>,
+	pub key: String,
+	pub security: ClientSecurity,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientListResponse {
+	pub data: Vec<ClientData>,
+	pub response: String,
+	pub result: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientKeyRequest {
+	pub key: String,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientResponse {
+	pub data: Option<ClientData>,
+	pub response: String,
+	pub result: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientRsa {
+	pub private: String,
+	pub public: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientSecurity {
+	pub access: Access,
+	#[serde(default)]
+	pub scheme: Scheme,
+	pub timestamp: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SaveClientRequest {
+	#[serde(default)]
+	pub deny: Vec<String>,
+	pub ip: Vec<String>,
+	pub key: String,
+	pub security: ClientSecurity,
+	pub size: Option<usize>,
+	pub token: Option<String>,
+}
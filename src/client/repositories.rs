@@ -0,0 +1,93 @@
+use crate::client::models::Client;
+use crate::core::database::models::UserReference;
+use crate::core::database::services::timestamp;
+use bson::{doc, Document};
+use log::error;
+use mongodb::error::Error;
+use mongodb::options::IndexOptions;
+use mongodb::results::{DeleteResult, InsertOneResult, UpdateResult};
+use mongodb::{Database, IndexModel};
+use serenity::futures::TryStreamExt;
+
+pub async fn create_index(database: &Database) {
+	let index_model = IndexModel::builder()
+		.keys(doc! {"key": 1})
+		.options(
+			IndexOptions::builder()
+				.unique(true)
+				.name(Some("key_unique_".to_string()))
+				.build(),
+		)
+		.build();
+	match database
+		.collection::<Client>("client")
+		.create_index(index_model)
+		.await
+	{
+		Ok(_) => {}
+		Err(err) => {
+			error!("{:?}", err)
+		}
+	}
+}
+
+pub async fn delete_one_by_key(database: &Database, key: &str) -> Result<DeleteResult, Error> {
+	database
+		.collection::<Client>("client")
+		.delete_one(doc! {"key": key})
+		.await
+}
+
+pub async fn find(database: &Database) -> Option<Vec<Client>> {
+	match database
+		.collection::<Client>("client")
+		.find(doc! {})
+		.sort(doc! {"key": 1})
+		.await
+	{
+		Ok(cursor) => match cursor.try_collect().await {
+			Ok(client) => Some(client),
+			Err(err) => {
+				error!("{:?}", err);
+				None
+			}
+		},
+		Err(err) => {
+			error!("{:?}", err);
+			None
+		}
+	}
+}
+
+pub async fn find_one_by_key(database: &Database, key: &str) -> Option<Client> {
+	database
+		.collection::<Client>("client")
+		.find_one(doc! {"key": key})
+		.await
+		.unwrap_or_else(|err| {
+			error!("{:?}", err);
+			None
+		})
+}
+
+pub async fn insert_one(
+	database: &Database,
+	data: &Client,
+	user: Option<UserReference>,
+) -> Result<InsertOneResult, Error> {
+	database
+		.collection::<Document>("client")
+		.insert_one(timestamp(data, user, &true, None))
+		.await
+}
+
+pub async fn replace_one(
+	database: &Database,
+	data: &Client,
+	user: Option<UserReference>,
+) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("client")
+		.replace_one(doc! {"_id": data.id}, timestamp(data, user, &true, None))
+		.await
+}
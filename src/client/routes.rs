@@ -0,0 +1,26 @@
+use crate::client::services::{create, delete, list, rotate, suspend};
+use actix_web::{web, HttpResponse};
+
+pub fn config(config: &mut web::ServiceConfig) {
+	config.service(
+		web::resource("").route(web::post().to(create)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/").route(web::post().to(create)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/delete").route(web::post().to(delete)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/delete/").route(web::post().to(delete)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/list").route(web::post().to(list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/list/").route(web::post().to(list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/rotate").route(web::post().to(rotate)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/rotate/").route(web::post().to(rotate)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/suspend").route(web::post().to(suspend)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/suspend/").route(web::post().to(suspend)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	);
+}
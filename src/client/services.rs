@@ -0,0 +1,316 @@
+use crate::client::models::{
+	Client, ClientData, ClientFile, ClientKeyRequest, ClientListResponse, ClientResponse, ClientRsa,
+	SaveClientRequest,
+};
+use crate::client::repositories::{delete_one_by_key, find, find_one_by_key, insert_one, replace_one};
+use crate::core::authentication::models::AuthenticatedUser;
+use crate::core::authentication::services::parse_network;
+use crate::core::base::enums::Status;
+use crate::core::base::models::BaseResponse;
+use crate::core::config::models::AppConfig;
+use crate::core::database::models::UserReference;
+use crate::core::database::services::database_error;
+use crate::core::encryption::hmac::generate_secret;
+use crate::core::encryption::rsa::generate_key;
+use crate::core::error::enums::AppError;
+use actix_web::{web, HttpResponse};
+use bson::oid::ObjectId;
+use chrono::{DateTime, Utc};
+use log::{error, info};
+use mongodb::Database;
+use std::collections::HashMap;
+use std::fs::{read_dir, read_to_string};
+use std::sync::{OnceLock, RwLock};
+
+pub struct ClientCache {
+	pub clients: RwLock<HashMap<String, (Client, DateTime<Utc>)>>,
+}
+
+impl ClientCache {
+	fn new() -> Self {
+		ClientCache {
+			clients: RwLock::new(HashMap::new()),
+		}
+	}
+
+	pub fn instance() -> &'static Self {
+		static INSTANCE: OnceLock<ClientCache> = OnceLock::new();
+		INSTANCE.get_or_init(ClientCache::new)
+	}
+
+	pub fn get(&self, key: &str) -> Option<Client> {
+		let ttl: i64 = AppConfig::instance().client.cache_ttl;
+
+		match self.clients.read() {
+			Ok(clients) => clients
+				.get(key)
+				.filter(|(_, cached)| Utc::now().signed_duration_since(cached).num_seconds() < ttl)
+				.map(|(client, _)| client.clone()),
+			Err(err) => {
+				error!("{}", err);
+				None
+			}
+		}
+	}
+
+	pub fn remove(&self, key: &str) {
+		match self.clients.write() {
+			Ok(mut clients) => {
+				clients.remove(key);
+			}
+			Err(err) => error!("{}", err),
+		}
+	}
+
+	pub fn set(&self, client: &Client) {
+		match self.clients.write() {
+			Ok(mut clients) => {
+				clients.insert(client.key.clone(), (client.clone(), Utc::now()));
+			}
+			Err(err) => error!("{}", err),
+		}
+	}
+}
+
+impl From<&Client> for ClientData {
+	fn from(client: &Client) -> Self {
+		ClientData {
+			deny: client.deny.clone(),
+			ip: client.ip.clone(),
+			key: client.key.clone(),
+			public_key: client.rsa.public.clone(),
+			secret: None,
+			security: client.security.clone(),
+			status: client.status.clone(),
+		}
+	}
+}
+
+pub async fn get_client(database: Option<&Database>, key: &str) -> Option<Client> {
+	if let Some(client) = ClientCache::instance().get(key) {
+		return Some(client);
+	}
+
+	let client: Client = find_one_by_key(database?, key).await?;
+	ClientCache::instance().set(&client);
+
+	Some(client)
+}
+
+pub async fn create_client(
+	database: &Database,
+	data: &ClientFile,
+	size: usize,
+	user: Option<UserReference>,
+) -> Result<Client, AppError> {
+	if data.key.is_empty() || data.key.contains(['.', '/', '\\']) {
+		return Err(AppError::Validation("Invalid client key.".to_string()));
+	}
+
+	if let Some(entry) = data.ip.iter().chain(data.deny.iter()).find(|entry| entry.trim() != "*" && parse_network(entry.trim()).is_none()) {
+		return Err(AppError::Validation(format!("Invalid IP address {}.", entry)));
+	}
+
+	let (private, public): (String, String) = generate_key(&size).ok_or(AppError::Internal("Failed to generate client key.".to_string()))?;
+
+	let mut client: Client = Client {
+		deny: data.deny.clone(),
+		id: ObjectId::new(),
+		ip: data.ip.clone(),
+		key: data.key.clone(),
+		rsa: ClientRsa { private, public },
+		secret: generate_secret(),
+		security: data.security.clone(),
+		status: Status::Active,
+		..Default::default()
+	};
+
+	match insert_one(database, &client, user).await {
+		Ok(result) => {
+			client.id = result.inserted_id.as_object_id().unwrap_or(client.id);
+			ClientCache::instance().remove(&client.key);
+
+			Ok(client)
+		}
+		Err(err) => Err(database_error(err, "key")),
+	}
+}
+
+pub async fn delete_client(database: &Database, key: &str) -> Result<(), AppError> {
+	match delete_one_by_key(database, key).await {
+		Ok(result) if result.deleted_count > 0 => {
+			ClientCache::instance().remove(key);
+
+			Ok(())
+		}
+		Ok(_) => Err(AppError::NotFound("Client not found.".to_string())),
+		Err(err) => Err(database_error(err, "key")),
+	}
+}
+
+pub async fn import_client(database: &Database, directory: &str) -> Vec<(String, Result<(), AppError>)> {
+	let mut results: Vec<(String, Result<(), AppError>)> = Vec::new();
+
+	let entries = match read_dir(directory) {
+		Ok(entries) => entries,
+		Err(err) => {
+			error!("{:?}", err);
+
+			return results;
+		}
+	};
+
+	for entry in entries.flatten().filter(|entry| entry.path().is_dir()) {
+		let key: String = entry.file_name().to_string_lossy().to_string();
+		let result: Result<(), AppError> = import_client_directory(database, &key, &entry.path().to_string_lossy()).await;
+
+		match &result {
+			Ok(_) => info!("Client {} has been imported.", key),
+			Err(err) => error!("Failed to import client {}. {}", key, err),
+		}
+
+		results.push((key, result));
+	}
+
+	results
+}
+
+async fn import_client_directory(database: &Database, key: &str, path: &str) -> Result<(), AppError> {
+	let data: ClientFile = read_to_string(format!("{}/client.pld", path))
+		.ok()
+		.and_then(|file| serde_json::from_str(&file).ok())
+		.ok_or(AppError::Validation("Invalid client.pld file.".to_string()))?;
+
+	let client: Client = Client {
+		deny: data.deny,
+		id: ObjectId::new(),
+		ip: data.ip,
+		key: key.to_string(),
+		rsa: ClientRsa {
+			private: read_to_string(format!("{}/private-key.pem", path)).unwrap_or_default(),
+			public: read_to_string(format!("{}/public-key.pem", path)).unwrap_or_default(),
+		},
+		secret: generate_secret(),
+		security: data.security,
+		status: Status::Active,
+		..Default::default()
+	};
+
+	if client.rsa.private.is_empty() || client.rsa.public.is_empty() {
+		return Err(AppError::Validation("Missing key files.".to_string()));
+	}
+
+	match insert_one(database, &client, None).await {
+		Ok(_) => Ok(()),
+		Err(err) => Err(database_error(err, "key")),
+	}
+}
+
+pub async fn rotate_client(
+	database: &Database,
+	key: &str,
+	size: usize,
+	user: Option<UserReference>,
+) -> Result<Client, AppError> {
+	let mut client: Client = find_one_by_key(database, key).await.ok_or(AppError::NotFound("Client not found.".to_string()))?;
+
+	let (private, public): (String, String) = generate_key(&size).ok_or(AppError::Internal("Failed to generate client key.".to_string()))?;
+	client.rsa = ClientRsa { private, public };
+	client.secret = generate_secret();
+
+	update_client(database, client, user).await
+}
+
+pub async fn suspend_client(database: &Database, key: &str, user: Option<UserReference>) -> Result<Client, AppError> {
+	let mut client: Client = find_one_by_key(database, key).await.ok_or(AppError::NotFound("Client not found.".to_string()))?;
+	client.status = Status::Inactive;
+
+	update_client(database, client, user).await
+}
+
+async fn update_client(database: &Database, client: Client, user: Option<UserReference>) -> Result<Client, AppError> {
+	match replace_one(database, &client, user).await {
+		Ok(_) => {
+			ClientCache::instance().remove(&client.key);
+
+			Ok(client)
+		}
+		Err(err) => Err(database_error(err, "key")),
+	}
+}
+
+pub async fn create(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<SaveClientRequest>,
+) -> Result<HttpResponse, AppError> {
+	let data: SaveClientRequest = data.into_inner();
+
+	let client: Client = create_client(
+		database.get_ref(),
+		&ClientFile {
+			deny: data.deny,
+			ip: data.ip,
+			key: data.key,
+			security: data.security,
+		},
+		data.size.unwrap_or(2048),
+		Some(user.log.user),
+	).await?;
+
+	Ok(HttpResponse::Ok().json(get_client_response(&client, "Client has been created successfully.", true)))
+}
+
+pub async fn delete(
+	_user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<ClientKeyRequest>,
+) -> Result<HttpResponse, AppError> {
+	delete_client(database.get_ref(), &data.key).await?;
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Client has been deleted successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn list(_user: AuthenticatedUser, database: web::Data<Database>) -> Result<HttpResponse, AppError> {
+	let clients: Vec<Client> = find(database.get_ref()).await.ok_or(AppError::Internal("Failed to find clients.".to_string()))?;
+
+	Ok(HttpResponse::Ok().json(ClientListResponse {
+		data: clients.iter().map(ClientData::from).collect(),
+		response: "Client list retrieved successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn rotate(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<ClientKeyRequest>,
+) -> Result<HttpResponse, AppError> {
+	let client: Client = rotate_client(database.get_ref(), &data.key, 2048, Some(user.log.user)).await?;
+
+	Ok(HttpResponse::Ok().json(get_client_response(&client, "Client key has been rotated successfully.", true)))
+}
+
+pub async fn suspend(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<ClientKeyRequest>,
+) -> Result<HttpResponse, AppError> {
+	let client: Client = suspend_client(database.get_ref(), &data.key, Some(user.log.user)).await?;
+
+	Ok(HttpResponse::Ok().json(get_client_response(&client, "Client has been suspended successfully.", false)))
+}
+
+fn get_client_response(client: &Client, message: &str, secret: bool) -> ClientResponse {
+	ClientResponse {
+		data: Some(ClientData {
+			secret: secret.then(|| client.secret.clone()),
+			..ClientData::from(client)
+		}),
+		response: message.to_string(),
+		result: true,
+	}
+}
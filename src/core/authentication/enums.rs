@@ -1,4 +1,8 @@
+use crate::core::base::models::BaseResponse;
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
 use strum_macros::EnumIter;
 
 #[derive(Clone, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Serialize)]
@@ -7,3 +11,79 @@ pub enum Access {
 	#[default]
 	Public,
 }
+
+#[derive(Clone, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Serialize)]
+pub enum Scheme {
+	Hmac,
+	#[default]
+	Rsa,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum AuthenticationError {
+	AccessDenied,
+	InvalidPath,
+	InvalidPayload,
+	InvalidSession,
+	InvalidSignature,
+	InvalidToken,
+	PayloadTooLarge,
+	TokenExpired,
+	TokenReplayed,
+	UnauthorizedIp(String),
+	UnauthorizedKey(String),
+}
+
+impl AuthenticationError {
+	pub fn get_reason(&self) -> &'static str {
+		match self {
+			AuthenticationError::AccessDenied => "access_denied",
+			AuthenticationError::InvalidPath => "invalid_path",
+			AuthenticationError::InvalidPayload => "invalid_payload",
+			AuthenticationError::InvalidSession => "invalid_session",
+			AuthenticationError::InvalidSignature => "invalid_signature",
+			AuthenticationError::InvalidToken => "invalid_token",
+			AuthenticationError::PayloadTooLarge => "payload_too_large",
+			AuthenticationError::TokenExpired => "token_expired",
+			AuthenticationError::TokenReplayed => "token_replayed",
+			AuthenticationError::UnauthorizedIp(_) => "unauthorized_ip",
+			AuthenticationError::UnauthorizedKey(_) => "unauthorized_key",
+		}
+	}
+}
+
+impl Display for AuthenticationError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+		match self {
+			AuthenticationError::AccessDenied => write!(formatter, "Access denied."),
+			AuthenticationError::InvalidPath => write!(formatter, "Invalid path."),
+			AuthenticationError::InvalidPayload => write!(formatter, "Invalid request body."),
+			AuthenticationError::InvalidSession => write!(formatter, "Abnormal request detected."),
+			AuthenticationError::InvalidSignature => write!(formatter, "Invalid signature."),
+			AuthenticationError::InvalidToken => write!(formatter, "Invalid token."),
+			AuthenticationError::PayloadTooLarge => write!(formatter, "Request body is too large."),
+			AuthenticationError::TokenExpired => write!(formatter, "Token expired."),
+			AuthenticationError::TokenReplayed => write!(formatter, "Token has already been used."),
+			AuthenticationError::UnauthorizedIp(ip) => write!(formatter, "Unauthorized IP address {}.", ip),
+			AuthenticationError::UnauthorizedKey(key) => write!(formatter, "Unauthorized PLD key {}.", key),
+		}
+	}
+}
+
+impl ResponseError for AuthenticationError {
+	fn status_code(&self) -> StatusCode {
+		match self {
+			AuthenticationError::AccessDenied | AuthenticationError::UnauthorizedIp(_) => StatusCode::FORBIDDEN,
+			AuthenticationError::InvalidPayload => StatusCode::BAD_REQUEST,
+			AuthenticationError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+			_ => StatusCode::UNAUTHORIZED,
+		}
+	}
+
+	fn error_response(&self) -> HttpResponse {
+		HttpResponse::build(self.status_code()).json(BaseResponse {
+			response: self.to_string(),
+			result: false,
+		})
+	}
+}
@@ -0,0 +1,151 @@
+use crate::core::authentication::enums::{Access, AuthenticationError};
+use crate::core::authentication::models::{AuthenticatedClient, AuthenticationCredential, AuthenticationSession};
+use crate::core::authentication::services::authenticate;
+use crate::core::config::models::AppConfig;
+use crate::core::encryption::hmac::digest;
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::CONTENT_TYPE;
+use actix_web::{web, Error, HttpMessage, ResponseError};
+use mongodb::Database;
+use serde_json::{Map, Value};
+use serenity::futures::StreamExt;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+
+pub struct Authentication {
+	access: Access,
+}
+
+impl Authentication {
+	pub fn new(access: Access) -> Self {
+		Authentication { access }
+	}
+}
+
+impl<S, B> Transform<S, ServiceRequest> for Authentication
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<EitherBody<B>>;
+	type Error = Error;
+	type Transform = AuthenticationMiddleware<S>;
+	type InitError = ();
+	type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+	fn new_transform(&self, service: S) -> Self::Future {
+		ready(Ok(AuthenticationMiddleware {
+			access: self.access.clone(),
+			service: Rc::new(service),
+		}))
+	}
+}
+
+pub struct AuthenticationMiddleware<S> {
+	access: Access,
+	service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for AuthenticationMiddleware<S>
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<EitherBody<B>>;
+	type Error = Error;
+	type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+	forward_ready!(service);
+
+	fn call(&self, mut request: ServiceRequest) -> Self::Future {
+		let access: Access = self.access.clone();
+		let service: Rc<S> = Rc::clone(&self.service);
+
+		Box::pin(async move {
+			let bytes: web::Bytes = match get_bytes(&mut request).await {
+				Ok(bytes) => bytes,
+				Err(err) => {
+					Metrics::instance().increment(&Metric::AuthenticationFailures, &[("reason", err.get_reason())], 1.0);
+
+					return Ok(request.into_response(err.error_response()).map_into_right_body());
+				}
+			};
+			let body: Map<String, Value> = serde_json::from_slice(&bytes).unwrap_or_default();
+
+			let credential: AuthenticationCredential = AuthenticationCredential {
+				digest: digest(&bytes),
+				nonce: get_header(&request, "pld-nonce"),
+				signature: get_header(&request, "pld-signature"),
+				timestamp: get_header(&request, "pld-timestamp"),
+				token: get_value(&request, &body, "pld-token", "token"),
+			};
+			let authentication: Option<String> = get_value(&request, &body, "pld-authentication", "authentication");
+
+			let database: Option<web::Data<Database>> = request.app_data::<web::Data<Database>>().cloned();
+
+			match authenticate(
+				request.request(),
+				database.as_ref().map(|database| database.get_ref()),
+				&access,
+				&credential,
+			).await {
+				Ok(client) => {
+					request.extensions_mut().insert(AuthenticatedClient { client });
+					request.extensions_mut().insert(AuthenticationSession {
+						authentication: authentication.unwrap_or_default(),
+					});
+
+					service.call(request).await.map(ServiceResponse::map_into_left_body)
+				}
+				Err(err) => {
+					Metrics::instance().increment(&Metric::AuthenticationFailures, &[("reason", err.get_reason())], 1.0);
+
+					Ok(request.into_response(err.error_response()).map_into_right_body())
+				}
+			}
+		})
+	}
+}
+
+async fn get_bytes(request: &mut ServiceRequest) -> Result<web::Bytes, AuthenticationError> {
+	let json: bool = request
+		.headers()
+		.get(CONTENT_TYPE)
+		.and_then(|value| value.to_str().ok())
+		.is_some_and(|value| value.starts_with("application/json"));
+
+	if !json && !request.headers().contains_key("pld-signature") {
+		return Ok(web::Bytes::new());
+	}
+
+	let limit: usize = AppConfig::instance().authentication.payload_limit;
+	let mut payload: Payload = request.take_payload();
+	let mut bytes: web::BytesMut = web::BytesMut::new();
+
+	while let Some(chunk) = payload.next().await {
+		let chunk: web::Bytes = chunk.map_err(|_| AuthenticationError::InvalidPayload)?;
+
+		if bytes.len() + chunk.len() > limit {
+			return Err(AuthenticationError::PayloadTooLarge);
+		}
+
+		bytes.extend_from_slice(&chunk);
+	}
+
+	let bytes: web::Bytes = bytes.freeze();
+	request.set_payload(Payload::from(bytes.clone()));
+
+	Ok(bytes)
+}
+
+fn get_header(request: &ServiceRequest, header: &str) -> Option<String> {
+	request.headers().get(header).and_then(|value| value.to_str().ok()).map(String::from)
+}
+
+fn get_value(request: &ServiceRequest, body: &Map<String, Value>, header: &str, key: &str) -> Option<String> {
+	get_header(request, header).or_else(|| body.get(key).and_then(Value::as_str).map(String::from))
+}
@@ -1,2 +1,5 @@
 pub mod enums;
+pub mod middleware;
+pub mod models;
+pub mod repositories;
 pub mod services;
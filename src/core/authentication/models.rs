@@ -0,0 +1,37 @@
+use crate::client::models::Client;
+use crate::user::log::models::UserLog;
+use bson::serde_helpers::chrono_datetime_as_bson_datetime;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthenticatedClient {
+	pub client: Client,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthenticatedUser {
+	pub log: UserLog,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthenticationSession {
+	pub authentication: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthenticationCredential {
+	pub digest: String,
+	pub nonce: Option<String>,
+	pub signature: Option<String>,
+	pub timestamp: Option<String>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthenticationNonce {
+	#[serde(with = "chrono_datetime_as_bson_datetime")]
+	pub expired: DateTime<Utc>,
+	pub key: String,
+	pub nonce: String,
+}
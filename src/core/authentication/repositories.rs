@@ -0,0 +1,49 @@
+use crate::core::authentication::models::AuthenticationNonce;
+use bson::doc;
+use log::error;
+use mongodb::error::Error;
+use mongodb::options::IndexOptions;
+use mongodb::results::InsertOneResult;
+use mongodb::{Database, IndexModel};
+use std::time::Duration;
+
+pub async fn create_index(database: &Database) {
+	let index_models: Vec<IndexModel> = vec![
+		IndexModel::builder()
+			.keys(doc! {"key": 1, "nonce": 1})
+			.options(
+				IndexOptions::builder()
+					.unique(true)
+					.name(Some("key_nonce_unique_".to_string()))
+					.build(),
+			)
+			.build(),
+		IndexModel::builder()
+			.keys(doc! {"expired": 1})
+			.options(
+				IndexOptions::builder()
+					.expire_after(Some(Duration::from_secs(0)))
+					.name(Some("expired_ttl_".to_string()))
+					.build(),
+			)
+			.build(),
+	];
+
+	match database
+		.collection::<AuthenticationNonce>("authentication_nonce")
+		.create_indexes(index_models)
+		.await
+	{
+		Ok(_) => {}
+		Err(err) => {
+			error!("{:?}", err)
+		}
+	}
+}
+
+pub async fn insert_one(database: &Database, data: &AuthenticationNonce) -> Result<InsertOneResult, Error> {
+	database
+		.collection::<AuthenticationNonce>("authentication_nonce")
+		.insert_one(data)
+		.await
+}
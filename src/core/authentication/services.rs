@@ -1,113 +1,341 @@
-use crate::core::authentication::enums::Access;
-use crate::core::base::models::{BaseResponse, Client};
+use crate::core::authentication::enums::{Access, AuthenticationError, Scheme};
+use crate::core::authentication::models::{AuthenticatedClient, AuthenticatedUser, AuthenticationCredential, AuthenticationNonce, AuthenticationSession};
+use crate::core::authentication::repositories::insert_one;
+use crate::client::models::Client;
+use crate::client::services::get_client;
+use crate::core::base::enums::Status;
+use crate::core::config::enums::Backend;
+use crate::core::config::models::{AppConfig, AuthenticationConfig};
+use crate::core::database::services::is_duplicate_key;
+use crate::core::encryption::hmac::{get_message, verify};
 use crate::core::encryption::rsa::decrypt;
-use actix_web::dev::ConnectionInfo;
-use actix_web::HttpRequest;
+use crate::user::log::models::UserLog;
+use crate::user::log::repositories::find_one_by_authentication;
+use crate::user::repositories::find_one_by_id;
+use crate::user::services::is_session_active;
+use actix_web::dev::Payload;
+use actix_web::{web, FromRequest, HttpMessage, HttpRequest};
 use chrono::{DateTime, TimeDelta, Utc};
-use log::info;
-use serde::{Deserialize, Serialize};
-use serde_json::{from_value, to_value, Value};
-use std::cell::Ref;
+use ipnet::{IpNet, Ipv4Net};
+use log::{error, info};
+use mongodb::Database;
+use rand::Rng;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
-use std::fs::read_to_string;
+use std::net::IpAddr;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::sync::{OnceLock, RwLock};
 
-pub fn authenticate<T>(request: HttpRequest, access: &Access, data: &T) -> BaseResponse
-					   where
-						   T: Serialize + for<'de> Deserialize<'de>,
-{
-	let mut response: BaseResponse = BaseResponse::default();
+pub struct NonceStore {
+	pub nonces: RwLock<HashMap<(String, String), DateTime<Utc>>>,
+}
 
-	info!(
-        "Request to {}\nHeaders : {:?}\nBody: {}",
-        &request.path().to_string(),
-		&request.headers(),
-        serde_json::to_string(data).unwrap()
-    );
-
-	let client: Client = get_client_detail(&get_client_key(request.clone()));
-
-	if !client.key.is_empty() {
-		if access == &Access::Public && client.security.access == Access::Public {
-			if authenticate_ip(request.clone(), client.clone()) {
-				let tokens: Vec<String> = get_token(
-					client.clone(),
-					from_value(to_value(data).unwrap_or_default()).unwrap_or_default(),
-				);
-
-				if tokens.len() == 2 {
-					if authenticate_path(request.clone(), tokens.clone()) {
-						if authenticate_timestamp(client.clone(), tokens.clone()) {
-							response.response = "Request authenticated.".to_string();
-							response.result = true;
-						} else {
-							response.response = "Token expired.".to_string();
-						}
-					} else {
-						response.response = "Invalid path.".to_string();
+impl NonceStore {
+	fn new() -> Self {
+		NonceStore {
+			nonces: RwLock::new(HashMap::new()),
+		}
+	}
+
+	pub fn instance() -> &'static Self {
+		static INSTANCE: OnceLock<NonceStore> = OnceLock::new();
+		INSTANCE.get_or_init(NonceStore::new)
+	}
+
+	pub fn insert(&self, nonce: AuthenticationNonce) -> bool {
+		match self.nonces.write() {
+			Ok(mut nonces) => {
+				let now: DateTime<Utc> = Utc::now();
+
+				match nonces.entry((nonce.key, nonce.nonce)) {
+					Entry::Occupied(mut entry) if *entry.get() <= now => {
+						entry.insert(nonce.expired);
+
+						true
+					}
+					Entry::Occupied(_) => false,
+					Entry::Vacant(entry) => {
+						entry.insert(nonce.expired);
+
+						true
 					}
-				} else {
-					response.response = "Invalid token.".to_string();
 				}
-			} else {
-				response.response = format!("Unauthorized IP address {}.", get_client_ip(request.clone()));
 			}
-		} else if client.security.access == Access::Private {
-			if authenticate_ip(request.clone(), client.clone()) {
-				response.response = "Request authenticated.".to_string();
-				response.result = true;
-			} else {
-				response.response = format!("Unauthorized IP address {}.", get_client_ip(request.clone()));
+			Err(err) => {
+				error!("{}", err);
+
+				false
 			}
-		} else {
-			response.response = "Access denied.".to_string();
 		}
-	} else {
-		response.response = format!("Unauthorized PLD key {}.", get_client_key(request.clone()));
 	}
 
-	response
+	pub fn purge(&self) {
+		match self.nonces.write() {
+			Ok(mut nonces) => {
+				let now: DateTime<Utc> = Utc::now();
+				nonces.retain(|_, expired| *expired > now);
+			}
+			Err(err) => error!("{}", err),
+		}
+	}
 }
 
-fn authenticate_ip(request: HttpRequest, client: Client) -> bool {
-	client.ip.contains(&get_client_ip(request.clone()).to_string()) || client.ip.contains(&"*".to_string())
+impl FromRequest for AuthenticatedClient {
+	type Error = AuthenticationError;
+	type Future = Ready<Result<Self, Self::Error>>;
+
+	fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+		ready(request.extensions().get::<AuthenticatedClient>().cloned().ok_or(AuthenticationError::InvalidToken))
+	}
 }
 
-fn authenticate_path(request: HttpRequest, tokens: Vec<String>) -> bool {
-	tokens.get(0).unwrap() == &request.path().to_string()
+impl FromRequest for AuthenticatedUser {
+	type Error = AuthenticationError;
+	type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+	fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+		if let Some(user) = request.extensions().get::<AuthenticatedUser>().cloned() {
+			return Box::pin(async move { Ok(user) });
+		}
+
+		let session: AuthenticationSession = request.extensions().get::<AuthenticationSession>().cloned().unwrap_or_default();
+		let database: Option<web::Data<Database>> = request.app_data::<web::Data<Database>>().cloned();
+
+		Box::pin(async move {
+			match database {
+				Some(database) if !session.authentication.is_empty() => {
+					let log: UserLog = match find_one_by_authentication(database.get_ref(), &session.authentication).await {
+						Some(log) if is_session_active(&log) => log,
+						_ => return Err(AuthenticationError::InvalidSession),
+					};
+
+					match find_one_by_id(database.get_ref(), &log.user.id).await {
+						Some(user) if user.status == Status::Active => Ok(AuthenticatedUser { log }),
+						_ => Err(AuthenticationError::InvalidSession),
+					}
+				}
+				_ => Err(AuthenticationError::InvalidSession),
+			}
+		})
+	}
 }
 
-fn authenticate_timestamp(client: Client, tokens: Vec<String>) -> bool {
-	let current_timestamp: DateTime<Utc> = Utc::now();
+pub async fn authenticate(
+	request: &HttpRequest,
+	database: Option<&Database>,
+	access: &Access,
+	credential: &AuthenticationCredential,
+) -> Result<Client, AuthenticationError> {
+	let key: String = get_client_key(request);
 
-	if client.security.timestamp {
-		let difference: TimeDelta = DateTime::parse_from_rfc3339(tokens.get(1).unwrap()).ok().unwrap_or_default().to_utc().signed_duration_since(current_timestamp);
+	info!(
+		"Request to {}\nClient : {}\nIP : {}",
+		request.path(),
+		key,
+		get_client_ip(request)
+	);
+
+	let client: Client = match get_client_detail(database, &key).await {
+		Some(client) => client,
+		None => return Err(AuthenticationError::UnauthorizedKey(key)),
+	};
+
+	if client.security.access == Access::Private {
+		return if authenticate_ip(request, &client) {
+			Ok(client)
+		} else {
+			Err(AuthenticationError::UnauthorizedIp(get_client_ip(request)))
+		};
+	}
+
+	if access != &Access::Public {
+		return Err(AuthenticationError::AccessDenied);
+	}
+
+	if !authenticate_ip(request, &client) {
+		return Err(AuthenticationError::UnauthorizedIp(get_client_ip(request)));
+	}
+
+	let tokens: Vec<String> = match client.security.scheme {
+		Scheme::Hmac => get_signature(request, &client, credential)?,
+		Scheme::Rsa => get_token(&client, credential.token.as_deref().unwrap_or_default()),
+	};
+
+	if tokens.len() != 3 || tokens[2].is_empty() || tokens[2].len() > 64 {
+		return Err(AuthenticationError::InvalidToken);
+	}
+
+	if !authenticate_path(request, &tokens) {
+		return Err(AuthenticationError::InvalidPath);
+	}
+
+	if !authenticate_timestamp(&client, &tokens) {
+		return Err(AuthenticationError::TokenExpired);
+	}
+
+	if !authenticate_nonce(database, &client, &tokens).await {
+		return Err(AuthenticationError::TokenReplayed);
+	}
 
-		difference.num_seconds() <= 5
+	Ok(client)
+}
+
+fn authenticate_ip(request: &HttpRequest, client: &Client) -> bool {
+	let ip: String = get_client_ip(request);
+
+	!match_ip(&ip, &client.deny) && match_ip(&ip, &client.ip)
+}
+
+pub fn match_ip(ip: &str, list: &[String]) -> bool {
+	let ip: Option<IpAddr> = ip.parse::<IpAddr>().ok().map(|ip| ip.to_canonical());
+
+	list.iter().any(|entry| match entry.trim() {
+		"*" => true,
+		entry => match (ip, parse_network(entry)) {
+			(Some(ip), Some(network)) => network.contains(&ip),
+			_ => false,
+		},
+	})
+}
+
+pub fn parse_network(entry: &str) -> Option<IpNet> {
+	entry
+		.parse::<IpNet>()
+		.ok()
+		.or_else(|| entry.parse::<IpAddr>().ok().map(|ip| IpNet::from(ip.to_canonical())))
+		.map(|network| match network {
+			IpNet::V6(network) => match network.addr().to_ipv4_mapped() {
+				Some(ip) => Ipv4Net::new(ip, network.prefix_len().saturating_sub(96)).map(IpNet::V4).unwrap_or(IpNet::V6(network)),
+				None => IpNet::V6(network),
+			},
+			network => network,
+		})
+}
+
+pub fn resolve_ip(peer: Option<IpAddr>, forwarded: Option<&str>, proxies: &[String]) -> String {
+	let Some(peer) = peer.map(|peer| peer.to_canonical()) else {
+		return String::new();
+	};
+
+	if !match_ip(&peer.to_string(), proxies) {
+		return peer.to_string();
+	}
+
+	forwarded
+		.unwrap_or_default()
+		.rsplit(',')
+		.map(str::trim)
+		.filter_map(|ip| ip.parse::<IpAddr>().ok().map(|ip| ip.to_canonical()))
+		.find(|ip| !match_ip(&ip.to_string(), proxies))
+		.unwrap_or(peer)
+		.to_string()
+}
+
+fn authenticate_path(request: &HttpRequest, tokens: &[String]) -> bool {
+	tokens[0] == request.path()
+}
+
+fn authenticate_timestamp(client: &Client, tokens: &[String]) -> bool {
+	let config: AuthenticationConfig = AppConfig::instance().authentication;
+
+	match DateTime::parse_from_rfc3339(&tokens[1]) {
+		Ok(timestamp) => {
+			let difference: i64 = Utc::now().signed_duration_since(timestamp.to_utc()).num_seconds();
+			let age: i64 = if client.security.timestamp { config.token_window } else { config.nonce_ttl };
+
+			difference >= -config.token_window && difference <= age
+		}
+		Err(_) => false,
+	}
+}
+
+async fn authenticate_nonce(database: Option<&Database>, client: &Client, tokens: &[String]) -> bool {
+	let nonce: AuthenticationNonce = AuthenticationNonce {
+		expired: get_nonce_expired(client),
+		key: client.key.clone(),
+		nonce: tokens[2].clone(),
+	};
+
+	match database {
+		Some(database) if AppConfig::instance().authentication.nonce_store == Backend::Mongodb => {
+			match insert_one(database, &nonce).await {
+				Ok(_) => true,
+				Err(err) => {
+					if !is_duplicate_key(&err) {
+						error!("{:?}", err);
+					}
+
+					false
+				}
+			}
+		}
+		_ => NonceStore::instance().insert(nonce),
+	}
+}
+
+pub fn get_nonce_expired(client: &Client) -> DateTime<Utc> {
+	let config: AuthenticationConfig = AppConfig::instance().authentication;
+
+	if client.security.timestamp {
+		Utc::now() + TimeDelta::seconds(config.token_window * 2)
 	} else {
-		true
+		Utc::now() + TimeDelta::seconds(config.nonce_ttl + config.token_window)
 	}
 }
 
-fn get_client_ip(request: HttpRequest) -> String {
-	let connection_info: Ref<ConnectionInfo> = request.connection_info();
+pub fn generate_nonce() -> String {
+	hex::encode(rand::thread_rng().r#gen::<[u8; 16]>())
+}
 
-	connection_info.realip_remote_addr().unwrap_or_default().to_string()
+pub fn is_trusted_proxy(request: &HttpRequest) -> bool {
+	request
+		.peer_addr()
+		.is_some_and(|address| match_ip(&address.ip().to_canonical().to_string(), &AppConfig::instance().authentication.trusted_proxies))
 }
 
-fn get_client_key(request: HttpRequest) -> String {
-	request.headers().get("pld-key").unwrap().to_str().unwrap_or_default().to_string()
+pub fn get_client_ip(request: &HttpRequest) -> String {
+	resolve_ip(
+		request.peer_addr().map(|address| address.ip()),
+		request.headers().get("x-forwarded-for").and_then(|value| value.to_str().ok()),
+		&AppConfig::instance().authentication.trusted_proxies,
+	)
 }
 
-fn get_client_detail(key: &str) -> Client {
-	match read_to_string(&format!("clients/{}/client.pld", key)) {
-		Ok(file) => serde_json::from_str(&file).unwrap_or_default(),
-		Err(_) => Client::default(),
+fn get_client_key(request: &HttpRequest) -> String {
+	request.headers().get("pld-key").and_then(|value| value.to_str().ok()).unwrap_or_default().to_string()
+}
+
+async fn get_client_detail(database: Option<&Database>, key: &str) -> Option<Client> {
+	if key.is_empty() {
+		return None;
 	}
+
+	get_client(database, key).await.filter(|client| client.status == Status::Active)
 }
 
-fn get_token(client: Client, map: HashMap<String, Value>) -> Vec<String> {
-	decrypt(
-		&map.get("token").unwrap().as_str().unwrap(),
-		&client.key.to_string(),
-	).split("~").map(String::from).collect()
+fn get_signature(
+	request: &HttpRequest,
+	client: &Client,
+	credential: &AuthenticationCredential,
+) -> Result<Vec<String>, AuthenticationError> {
+	let (Some(timestamp), Some(nonce), Some(signature)) = (&credential.timestamp, &credential.nonce, &credential.signature) else {
+		return Err(AuthenticationError::InvalidSignature);
+	};
+
+	let message: String = get_message(request.method().as_str(), request.path(), request.query_string(), timestamp, nonce, &credential.digest);
+
+	if client.secret.is_empty() || !verify(&message, &client.secret, signature) {
+		return Err(AuthenticationError::InvalidSignature);
+	}
+
+	Ok(vec![request.path().to_string(), timestamp.clone(), nonce.clone()])
+}
+
+fn get_token(client: &Client, token: &str) -> Vec<String> {
+	if token.is_empty() {
+		return Vec::new();
+	}
+
+	decrypt(token, &client.rsa.private).split("~").map(String::from).collect()
 }
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Deserialize, EnumIter, Eq, Hash, PartialEq, Serialize)]
+pub enum Permission {
+	ManageAssets,
+	ManageClients,
+	ManageRoles,
+	ManageUsers,
+	RunBacktests,
+	ViewData,
+	ViewDiagnostics,
+}
@@ -0,0 +1,83 @@
+use crate::core::authentication::models::{AuthenticatedUser, AuthenticationSession};
+use crate::core::authorization::enums::Permission;
+use crate::core::authorization::services::authorize;
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{web, Error, HttpMessage, ResponseError};
+use log::warn;
+use mongodb::Database;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+
+pub struct Authorization {
+	permission: Permission,
+}
+
+impl Authorization {
+	pub fn new(permission: Permission) -> Self {
+		Authorization { permission }
+	}
+}
+
+impl<S, B> Transform<S, ServiceRequest> for Authorization
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<EitherBody<B>>;
+	type Error = Error;
+	type Transform = AuthorizationMiddleware<S>;
+	type InitError = ();
+	type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+	fn new_transform(&self, service: S) -> Self::Future {
+		ready(Ok(AuthorizationMiddleware {
+			permission: self.permission,
+			service: Rc::new(service),
+		}))
+	}
+}
+
+pub struct AuthorizationMiddleware<S> {
+	permission: Permission,
+	service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for AuthorizationMiddleware<S>
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<EitherBody<B>>;
+	type Error = Error;
+	type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+	forward_ready!(service);
+
+	fn call(&self, request: ServiceRequest) -> Self::Future {
+		let permission: Permission = self.permission;
+		let service: Rc<S> = Rc::clone(&self.service);
+
+		Box::pin(async move {
+			let database: Option<web::Data<Database>> = request.app_data::<web::Data<Database>>().cloned();
+			let session: AuthenticationSession = request.extensions().get::<AuthenticationSession>().cloned().unwrap_or_default();
+
+			match authorize(database.as_ref().map(|database| database.get_ref()), &session.authentication, &permission).await {
+				Ok((log, _)) => {
+					request.extensions_mut().insert(AuthenticatedUser { log });
+
+					service.call(request).await.map(ServiceResponse::map_into_left_body)
+				}
+				Err(err) => {
+					warn!("Authorization for {:?} on {} failed. {}", permission, request.path(), err);
+					Metrics::instance().increment(&Metric::AuthenticationFailures, &[("reason", err.get_reason())], 1.0);
+
+					Ok(request.into_response(err.error_response()).map_into_right_body())
+				}
+			}
+		})
+	}
+}
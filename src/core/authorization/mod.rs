@@ -0,0 +1,3 @@
+pub mod enums;
+pub mod middleware;
+pub mod services;
@@ -0,0 +1,56 @@
+use crate::core::authentication::enums::AuthenticationError;
+use crate::core::authorization::enums::Permission;
+use crate::core::base::enums::Status;
+use crate::user::enums::UserType;
+use crate::user::log::models::UserLog;
+use crate::user::log::repositories::find_one_by_authentication;
+use crate::user::models::User;
+use crate::user::repositories::find_one_by_id;
+use crate::user::services::is_session_active;
+use mongodb::Database;
+use strum::IntoEnumIterator;
+
+pub fn get_permissions(user_type: &UserType) -> Vec<Permission> {
+	match user_type {
+		UserType::Owner => Permission::iter().collect(),
+		UserType::Administrator => vec![
+			Permission::ManageAssets,
+			Permission::ManageUsers,
+			Permission::RunBacktests,
+			Permission::ViewData,
+			Permission::ViewDiagnostics,
+		],
+		UserType::Member => vec![Permission::RunBacktests, Permission::ViewData],
+	}
+}
+
+pub fn has_permission(user_type: &UserType, permission: &Permission) -> bool {
+	get_permissions(user_type).contains(permission)
+}
+
+pub async fn authorize(
+	database: Option<&Database>,
+	authentication: &str,
+	permission: &Permission,
+) -> Result<(UserLog, User), AuthenticationError> {
+	let database: &Database = match database {
+		Some(database) if !authentication.is_empty() => database,
+		_ => return Err(AuthenticationError::InvalidSession),
+	};
+
+	let log: UserLog = match find_one_by_authentication(database, authentication).await {
+		Some(log) if is_session_active(&log) => log,
+		_ => return Err(AuthenticationError::InvalidSession),
+	};
+
+	let user: User = match find_one_by_id(database, &log.user.id).await {
+		Some(user) if user.status == Status::Active => user,
+		_ => return Err(AuthenticationError::InvalidSession),
+	};
+
+	if !has_permission(&user._type, permission) {
+		return Err(AuthenticationError::AccessDenied);
+	}
+
+	Ok((log, user))
+}
@@ -1,17 +1,17 @@
+use crate::core::config::models::AppConfig;
+use crate::core::error::enums::AppError;
 use actix_web::http::header::ContentType;
-use actix_web::{web, HttpResponse, Responder};
-use std::env;
+use actix_web::{web, HttpResponse};
 use tera::{Context, Tera};
 
-pub async fn index(template: web::Data<Tera>) -> impl Responder {
+pub async fn index(template: web::Data<Tera>, config: web::Data<AppConfig>) -> Result<HttpResponse, AppError> {
     let mut context: Context = Context::new();
 
-    let application_name: String = env::var("APPLICATION_NAME").unwrap_or_default();
-    context.insert("title", &application_name);
+    context.insert("title", &config.application.name);
 
-    let body: String = template.render("index.html", &context).unwrap();
+    let body: String = template.render("index.html", &context)?;
 
-    HttpResponse::Ok()
+    Ok(HttpResponse::Ok()
         .content_type(ContentType::html())
-        .body(body)
+        .body(body))
 }
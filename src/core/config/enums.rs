@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Backend {
+	#[default]
+	Memory,
+	Mongodb,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ConfigError {
+	Invalid { key: String, reason: String },
+	Missing(String),
+}
+
+impl Display for ConfigError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+		match self {
+			ConfigError::Invalid { key, reason } => write!(formatter, "{} is invalid: {}", key, reason),
+			ConfigError::Missing(key) => write!(formatter, "{} is required", key),
+		}
+	}
+}
+
+impl Error for ConfigError {}
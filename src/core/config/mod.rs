@@ -0,0 +1,3 @@
+pub mod enums;
+pub mod models;
+pub mod services;
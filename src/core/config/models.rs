@@ -0,0 +1,165 @@
+use crate::core::config::enums::Backend;
+use crate::integration::enums::{IntegrationMode, Vendor};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AppConfig {
+	pub ai: VendorConfig,
+	pub application: ApplicationConfig,
+	pub authentication: AuthenticationConfig,
+	pub client: ClientConfig,
+	pub database: DatabaseConfig,
+	pub discord: DiscordConfig,
+	pub email: EmailConfig,
+	pub eodhd: VendorConfig,
+	pub health: HealthConfig,
+	pub integration: IntegrationConfig,
+	pub log: LogConfig,
+	pub massive: VendorConfig,
+	pub metrics: MetricsConfig,
+	pub rate_limit: RateLimitConfig,
+	pub server: ServerConfig,
+	pub user: UserConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplicationConfig {
+	pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthenticationConfig {
+	pub nonce_store: Backend,
+	pub nonce_ttl: i64,
+	pub payload_limit: usize,
+	pub token_window: i64,
+	pub trusted_proxies: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClientConfig {
+	pub cache_ttl: i64,
+	pub directory: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DatabaseConfig {
+	pub connection_string: String,
+	pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DiscordConfig {
+	pub channel_id: u64,
+	pub channel_id_security: u64,
+	pub channel_id_visitor_message: u64,
+	pub token: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EmailConfig {
+	pub password: String,
+	pub smtp_port: u16,
+	pub smtp_server: String,
+	pub user: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthConfig {
+	pub error_history: usize,
+	pub scheduler_max_age: i64,
+	pub sync_max_age: i64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IntegrationConfig {
+	pub backoff: u64,
+	pub backoff_max: u64,
+	pub cassette_directory: String,
+	pub circuit_cooldown: i64,
+	pub circuit_threshold: u32,
+	pub mode: IntegrationMode,
+	pub retry: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LogConfig {
+	pub integration: LogIntegrationConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LogIntegrationConfig {
+	pub body_limit: usize,
+	pub level: HashMap<Vendor, String>,
+	pub secret_headers: Vec<String>,
+	pub secret_params: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MetricsConfig {
+	pub token: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RateLimitConfig {
+	pub backend: Backend,
+	pub scopes: HashMap<String, RateLimitScopeConfig>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RateLimitScopeConfig {
+	pub capacity: f64,
+	pub quota: u64,
+	pub refill: f64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ServerConfig {
+	pub address: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserAvatarConfig {
+	pub cache_ttl: u64,
+	pub directory: String,
+	pub max_dimension: u32,
+	pub max_size: usize,
+	pub sizes: Vec<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserConfig {
+	pub avatar: UserAvatarConfig,
+	pub password: UserPasswordConfig,
+	pub security: UserSecurityConfig,
+	pub session: UserSessionConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserPasswordConfig {
+	pub attempt: u32,
+	pub lock: i64,
+	pub recovery_ttl: i64,
+	pub recovery_url: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSecurityConfig {
+	pub country_header: String,
+	pub history: i64,
+	pub travel_window: i64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSessionConfig {
+	pub remember_ttl: i64,
+	pub ttl: i64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VendorConfig {
+	pub base_url: String,
+	pub key: String,
+	pub timeout: u64,
+}
@@ -0,0 +1,345 @@
+use crate::core::authentication::services::parse_network;
+use crate::core::config::enums::{Backend, ConfigError};
+use crate::core::config::models::{
+	AppConfig, ApplicationConfig, AuthenticationConfig, ClientConfig, DatabaseConfig, DiscordConfig, EmailConfig, HealthConfig, IntegrationConfig,
+	LogConfig, LogIntegrationConfig, MetricsConfig, RateLimitConfig, RateLimitScopeConfig, ServerConfig, UserAvatarConfig, UserConfig,
+	UserPasswordConfig, UserSecurityConfig, UserSessionConfig, VendorConfig,
+};
+use crate::integration::enums::{IntegrationMode, Vendor};
+use std::collections::HashMap;
+use std::env;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::RwLock;
+use strum::IntoEnumIterator;
+
+static INSTANCE: RwLock<Option<AppConfig>> = RwLock::new(None);
+
+struct ConfigReader {
+	errors: Vec<ConfigError>,
+	values: HashMap<String, String>,
+}
+
+impl ConfigReader {
+	fn new(values: HashMap<String, String>) -> Self {
+		ConfigReader {
+			errors: Vec::new(),
+			values,
+		}
+	}
+
+	fn optional(&self, key: &str) -> String {
+		self.values.get(key).map(|value| value.trim().to_string()).unwrap_or_default()
+	}
+
+	fn text(&self, key: &str, default: &str) -> String {
+		let value: String = self.optional(key);
+
+		if value.is_empty() { default.to_string() } else { value }
+	}
+
+	fn list(&self, key: &str, default: &str) -> Vec<String> {
+		self.text(key, default).split(',').map(str::trim).filter(|value| !value.is_empty()).map(String::from).collect()
+	}
+
+	fn secrets(&self, key: &str, default: &str) -> Vec<String> {
+		self.list(key, default).iter().map(|secret| secret.to_lowercase()).collect()
+	}
+
+	fn required(&mut self, key: &str) -> String {
+		let value: String = self.optional(key);
+
+		if value.is_empty() {
+			self.errors.push(ConfigError::Missing(key.to_string()));
+		}
+
+		value
+	}
+
+	fn parse<T: FromStr>(&mut self, key: &str, default: T) -> T {
+		let value: String = self.optional(key);
+
+		if value.is_empty() {
+			return default;
+		}
+
+		value.parse().unwrap_or_else(|_| {
+			self.invalid(key, &format!("'{}' is not a valid number", value));
+			default
+		})
+	}
+
+	fn numbers<T: FromStr>(&mut self, key: &str, default: &str) -> Vec<T> {
+		let mut numbers: Vec<T> = Vec::new();
+
+		for value in self.list(key, default) {
+			match value.parse() {
+				Ok(number) => numbers.push(number),
+				Err(_) => self.invalid(key, &format!("'{}' is not a valid number", value)),
+			}
+		}
+
+		numbers
+	}
+
+	fn backend(&mut self, key: &str) -> Backend {
+		match self.optional(key).to_lowercase().as_str() {
+			"" | "memory" => Backend::Memory,
+			"mongodb" => Backend::Mongodb,
+			_ => {
+				self.invalid(key, "must be memory or mongodb");
+				Backend::Memory
+			}
+		}
+	}
+
+	fn mode(&mut self, key: &str) -> IntegrationMode {
+		match self.optional(key).to_lowercase().as_str() {
+			"" | "live" => IntegrationMode::Live,
+			"record" => IntegrationMode::Record,
+			"replay" => IntegrationMode::Replay,
+			_ => {
+				self.invalid(key, "must be live, record or replay");
+				IntegrationMode::Live
+			}
+		}
+	}
+
+	fn level(&mut self, key: &str) -> String {
+		let level: String = self.text(key, "info").to_lowercase();
+
+		match level.as_str() {
+			"off" | "error" | "warn" | "info" | "debug" | "trace" => level,
+			_ => {
+				self.invalid(key, "must be off, error, warn, info, debug or trace");
+				"info".to_string()
+			}
+		}
+	}
+
+	fn networks(&mut self, key: &str) -> Vec<String> {
+		let networks: Vec<String> = self.list(key, "");
+
+		for network in &networks {
+			if network != "*" && parse_network(network).is_none() {
+				self.invalid(key, &format!("'{}' is not an IP address or network", network));
+			}
+		}
+
+		networks
+	}
+
+	fn scopes(&mut self, prefix: &str) -> HashMap<String, RateLimitScopeConfig> {
+		let mut scopes: Vec<String> = self
+			.values
+			.keys()
+			.filter_map(|key| key.strip_prefix(prefix))
+			.filter_map(|key| key.strip_suffix("_CAPACITY").or(key.strip_suffix("_QUOTA")).or(key.strip_suffix("_REFILL")))
+			.map(String::from)
+			.collect();
+		scopes.sort();
+		scopes.dedup();
+
+		scopes
+			.into_iter()
+			.map(|scope| {
+				let capacity: f64 = self.parse(&format!("{}{}_CAPACITY", prefix, scope), 0.0);
+				let refill: f64 = self.parse(&format!("{}{}_REFILL", prefix, scope), capacity);
+
+				if capacity < 0.0 || refill < 0.0 {
+					self.invalid(&format!("{}{}", prefix, scope), "capacity and refill must not be negative");
+				}
+
+				let config: RateLimitScopeConfig = RateLimitScopeConfig {
+					capacity,
+					quota: self.parse(&format!("{}{}_QUOTA", prefix, scope), 0),
+					refill: refill / 60.0,
+				};
+
+				(scope.to_lowercase(), config)
+			})
+			.collect()
+	}
+
+	fn url(&mut self, key: &str) -> String {
+		let value: String = self.optional(key).trim_end_matches('/').to_string();
+
+		if !value.is_empty() && !value.starts_with("http://") && !value.starts_with("https://") {
+			self.invalid(key, "must start with http:// or https://");
+		}
+
+		value
+	}
+
+	fn vendor(&mut self, base_url: &str, key: &str, timeout: &str) -> VendorConfig {
+		let config: VendorConfig = VendorConfig {
+			base_url: self.url(base_url),
+			key: self.optional(key),
+			timeout: self.parse(timeout, 30),
+		};
+
+		if !config.base_url.is_empty() && config.key.is_empty() {
+			self.errors.push(ConfigError::Missing(key.to_string()));
+		}
+
+		config
+	}
+
+	fn invalid(&mut self, key: &str, reason: &str) {
+		self.errors.push(ConfigError::Invalid {
+			key: key.to_string(),
+			reason: reason.to_string(),
+		});
+	}
+
+	fn read(mut self) -> (AppConfig, Vec<ConfigError>) {
+		let config: AppConfig = AppConfig {
+			ai: self.vendor("API_AI_BASE_URL", "API_AI_KEY", "API_AI_TIMEOUT"),
+			application: ApplicationConfig {
+				name: self.required("APPLICATION_NAME"),
+			},
+			authentication: AuthenticationConfig {
+				nonce_store: self.backend("AUTHENTICATION_NONCE_STORE"),
+				nonce_ttl: self.parse("AUTHENTICATION_NONCE_TTL", 86400),
+				payload_limit: self.parse("AUTHENTICATION_PAYLOAD_LIMIT", 4194304),
+				token_window: self.parse("AUTHENTICATION_TOKEN_WINDOW", 30),
+				trusted_proxies: self.networks("AUTHENTICATION_TRUSTED_PROXIES"),
+			},
+			client: ClientConfig {
+				cache_ttl: self.parse("CLIENT_CACHE_TTL", 60),
+				directory: self.text("CLIENT_DIRECTORY", "clients"),
+			},
+			database: DatabaseConfig {
+				connection_string: self.required("DATABASE_CONNECTION_STRING"),
+				name: self.required("DATABASE_NAME"),
+			},
+			discord: DiscordConfig {
+				channel_id: self.parse("API_DISCORD_CHANNEL_ID", 0),
+				channel_id_security: self.parse("API_DISCORD_CHANNEL_ID_SECURITY", 0),
+				channel_id_visitor_message: self.parse("API_DISCORD_CHANNEL_ID_VISITOR_MESSAGE", 0),
+				token: self.optional("API_DISCORD_TOKEN"),
+			},
+			email: EmailConfig {
+				password: self.optional("EMAIL_PASSWORD"),
+				smtp_port: self.parse("EMAIL_SMTP_PORT", 0),
+				smtp_server: self.optional("EMAIL_SMTP_SERVER"),
+				user: self.optional("EMAIL_USER"),
+			},
+			eodhd: self.vendor("API_EODHD_BASE_URL", "API_EODHD_TOKEN", "API_EODHD_TIMEOUT"),
+			health: HealthConfig {
+				error_history: self.parse("HEALTH_ERROR_HISTORY", 50),
+				scheduler_max_age: self.parse("HEALTH_SCHEDULER_MAX_AGE", 300),
+				sync_max_age: self.parse("HEALTH_SYNC_MAX_AGE", 900),
+			},
+			integration: IntegrationConfig {
+				backoff: self.parse("API_INTEGRATION_BACKOFF", 500),
+				backoff_max: self.parse("API_INTEGRATION_BACKOFF_MAX", 30000),
+				cassette_directory: self.text("API_INTEGRATION_CASSETTE_DIRECTORY", "cassettes"),
+				circuit_cooldown: self.parse("API_INTEGRATION_CIRCUIT_COOLDOWN", 60),
+				circuit_threshold: self.parse("API_INTEGRATION_CIRCUIT_THRESHOLD", 5),
+				mode: self.mode("API_INTEGRATION_MODE"),
+				retry: self.parse("API_INTEGRATION_RETRY", 3),
+			},
+			log: LogConfig {
+				integration: LogIntegrationConfig {
+					body_limit: self.parse("LOG_INTEGRATION_BODY_LIMIT", 1024),
+					level: Vendor::iter()
+						.map(|vendor| (vendor, self.level(&format!("LOG_INTEGRATION_LEVEL_{:?}", vendor).to_uppercase())))
+						.collect(),
+					secret_headers: self.secrets("LOG_INTEGRATION_SECRET_HEADERS", "authorization,pld-key"),
+					secret_params: self.secrets("LOG_INTEGRATION_SECRET_PARAMS", "api_token,apikey,token"),
+				},
+			},
+			massive: self.vendor("API_MASSIVE_BASE_URL", "API_MASSIVE_KEY", "API_MASSIVE_TIMEOUT"),
+			metrics: MetricsConfig {
+				token: self.optional("METRICS_TOKEN"),
+			},
+			rate_limit: RateLimitConfig {
+				backend: self.backend("RATE_LIMIT_BACKEND"),
+				scopes: self.scopes("RATE_LIMIT_"),
+			},
+			server: ServerConfig {
+				address: self.required("SERVER_ADDRESS"),
+			},
+			user: UserConfig {
+				avatar: UserAvatarConfig {
+					cache_ttl: self.parse("USER_AVATAR_CACHE_TTL", 604800),
+					directory: self.text("USER_AVATAR_DIRECTORY", "avatar"),
+					max_dimension: self.parse("USER_AVATAR_MAX_DIMENSION", 4096),
+					max_size: self.parse("USER_AVATAR_MAX_SIZE", 2097152),
+					sizes: self.numbers("USER_AVATAR_SIZES", "64,128,256"),
+				},
+				password: UserPasswordConfig {
+					attempt: self.parse("USER_PASSWORD_ATTEMPT", 5),
+					lock: self.parse("USER_PASSWORD_LOCK", 900),
+					recovery_ttl: self.parse("USER_PASSWORD_RECOVERY_TTL", 3600),
+					recovery_url: self.url("USER_PASSWORD_RECOVERY_URL"),
+				},
+				security: UserSecurityConfig {
+					country_header: self.optional("USER_SECURITY_COUNTRY_HEADER"),
+					history: self.parse("USER_SECURITY_HISTORY", 90),
+					travel_window: self.parse("USER_SECURITY_TRAVEL_WINDOW", 7200),
+				},
+				session: UserSessionConfig {
+					remember_ttl: self.parse("USER_SESSION_REMEMBER_TTL", 2592000),
+					ttl: self.parse("USER_SESSION_TTL", 86400),
+				},
+			},
+		};
+
+		let connection_string: &str = &config.database.connection_string;
+
+		if !connection_string.is_empty() && !connection_string.starts_with("mongodb://") && !connection_string.starts_with("mongodb+srv://") {
+			self.invalid("DATABASE_CONNECTION_STRING", "must start with mongodb:// or mongodb+srv://");
+		}
+
+		if !config.server.address.is_empty() && config.server.address.parse::<SocketAddr>().is_err() {
+			self.invalid("SERVER_ADDRESS", "must be a socket address such as 0.0.0.0:5000");
+		}
+
+		if !config.email.smtp_server.is_empty() {
+			if config.email.smtp_port == 0 {
+				self.errors.push(ConfigError::Missing("EMAIL_SMTP_PORT".to_string()));
+			}
+
+			if !config.email.user.contains('@') {
+				self.invalid("EMAIL_USER", "must be an email address");
+			}
+
+			if config.user.password.recovery_url.is_empty() {
+				self.errors.push(ConfigError::Missing("USER_PASSWORD_RECOVERY_URL".to_string()));
+			}
+		}
+
+		(config, self.errors)
+	}
+}
+
+impl Default for AppConfig {
+	fn default() -> Self {
+		ConfigReader::new(HashMap::new()).read().0
+	}
+}
+
+impl AppConfig {
+	pub fn from_values(values: HashMap<String, String>) -> Result<AppConfig, Vec<ConfigError>> {
+		match ConfigReader::new(values).read() {
+			(config, errors) if errors.is_empty() => Ok(config),
+			(_, errors) => Err(errors),
+		}
+	}
+
+	pub fn load() -> Result<AppConfig, Vec<ConfigError>> {
+		AppConfig::from_values(env::vars().collect())
+	}
+
+	pub fn initialize(config: &AppConfig) {
+		if let Ok(mut instance) = INSTANCE.write() {
+			*instance = Some(config.clone());
+		}
+	}
+
+	pub fn instance() -> AppConfig {
+		INSTANCE.read().ok().and_then(|instance| instance.clone()).unwrap_or_default()
+	}
+}
@@ -1,19 +1,71 @@
 use crate::core::database::models::{Timestamp, UserReference};
+use crate::core::error::enums::AppError;
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
 use bson::{to_document, Bson, Document};
 use chrono::Utc;
 use mongodb::error::Error;
+use mongodb::event::command::CommandEvent;
+use mongodb::event::EventHandler;
+use mongodb::options::ClientOptions;
+use mongodb::Client;
 use serde::{Deserialize, Serialize};
 
-pub fn error_message(err: Error, unique_key: &str) -> String {
-	let error: String = format!("{:?}", err.kind.as_ref());
+pub fn archive<T>(data: &T, user: Option<UserReference>, log_key: &str) -> Document
+where
+	T: Serialize + for<'de> Deserialize<'de>,
+{
+	let mut document: Document = to_document(data).unwrap_or_default();
+
+	let archived: Document = to_document(&Timestamp {
+		timestamp: Utc::now(),
+		user: user.unwrap_or(UserReference {
+			username: "System".to_string(),
+			..Default::default()
+		}),
+	})
+	.unwrap_or_default();
 
-	if error.contains("code: 11000") && error.contains(unique_key) {
-		(unique_key[0..1].to_uppercase() + &unique_key[1..] + " already exists.").to_string()
+	document.insert("archived", archived);
+
+	if let Some(id) = document.remove("_id") {
+		document.insert(log_key, id);
+	}
+
+	document
+}
+
+pub fn database_error(err: Error, unique_key: &str) -> AppError {
+	if is_duplicate_key(&err) && format!("{:?}", err.kind.as_ref()).contains(unique_key) {
+		AppError::Conflict(unique_key[0..1].to_uppercase() + &unique_key[1..] + " already exists.")
 	} else {
-		"Internal server error.".to_string()
+		AppError::from(err)
 	}
 }
 
+pub fn is_duplicate_key(err: &Error) -> bool {
+	format!("{:?}", err.kind.as_ref()).contains("code: 11000")
+}
+
+pub async fn initialize_client(connection_string: &str) -> Result<Client, Error> {
+	let mut options: ClientOptions = ClientOptions::parse(connection_string).await?;
+	options.command_event_handler = Some(EventHandler::callback(|event: CommandEvent| match event {
+		CommandEvent::Succeeded(event) => Metrics::instance().observe(
+			&Metric::DatabaseOperationDuration,
+			&[("command", &event.command_name), ("status", "success")],
+			event.duration.as_secs_f64(),
+		),
+		CommandEvent::Failed(event) => Metrics::instance().observe(
+			&Metric::DatabaseOperationDuration,
+			&[("command", &event.command_name), ("status", "failure")],
+			event.duration.as_secs_f64(),
+		),
+		_ => {}
+	}));
+
+	Client::with_options(options)
+}
+
 pub fn timestamp<T>(
 	data: &T,
 	user: Option<UserReference>,
@@ -29,7 +81,7 @@ pub fn timestamp<T>(
 		..Default::default()
 	};
 
-	if !user.is_none() {
+	if user.is_some() {
 		user_reference = user.unwrap_or_default();
 	}
 
@@ -38,21 +90,17 @@ pub fn timestamp<T>(
 		user: user_reference,
 	}).unwrap_or_default();
 
-	if document.get("created").and_then(Bson::as_document).unwrap().get("timestamp").is_some()
-	{
+	if document.get("created").and_then(Bson::as_document).is_some_and(|created| created.get("timestamp").is_some()) {
 		document.insert("created", timestamp.clone());
 	}
 
 	document.insert("modified", timestamp);
 
-	if !log_key.is_none() {
-		document.insert(
-			log_key.unwrap_or_default(),
-			document.get("_id").unwrap().clone(),
-		);
+	if let (Some(log_key), Some(id)) = (log_key, document.get("_id").cloned()) {
+		document.insert(log_key, id);
 	}
 
-	if create.clone() {
+	if *create {
 		document.remove("_id");
 	}
 
@@ -1,28 +1,69 @@
-use crate::core::base::models::BaseResponse;
+use crate::core::config::models::{AppConfig, EmailConfig};
 use lettre::message::Mailbox;
 use lettre::transport::smtp::authentication::Credentials;
 use lettre::transport::smtp::client::{Tls, TlsParameters};
 use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
-use std::env;
+use log::{error, info, warn};
 
-pub async fn send(from: &str, to: &str, subject: &str, body: &str) {
-	let mut email: BaseResponse = BaseResponse::default();
+pub async fn send(from: &str, to: &str, subject: &str, body: &str) -> bool {
+	let config: EmailConfig = AppConfig::instance().email;
 
-	let smtp_server: String = env::var("EMAIL_SMTP_SERVER").unwrap_or_default();
-	let smtp_port: u16 = env::var("EMAIL_SMTP_PORT").unwrap_or_default().parse().unwrap_or_default();
-	let user: String = env::var("EMAIL_USER").unwrap_or_default();
-	let password: String = env::var("EMAIL_PASSWORD").unwrap_or_default();
+	if config.smtp_server.is_empty() {
+		warn!("Email to {} was not sent because SMTP is not configured.", to);
 
-	let message: Message = Message::builder().from(from.parse::<Mailbox>().unwrap()).to(to.parse::<Mailbox>().unwrap()).subject(subject).body(String::from(body)).unwrap();
-	let mailer: AsyncSmtpTransport<Tokio1Executor> = AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp_server).unwrap().port(smtp_port).tls(Tls::Required(
-		TlsParameters::new(smtp_server.to_string()).unwrap(),
-	)).credentials(Credentials::new(user.clone(), password)).build();
+		return false;
+	}
+
+	let (from, to): (Mailbox, Mailbox) = match (from.parse::<Mailbox>(), to.parse::<Mailbox>()) {
+		(Ok(from), Ok(to)) => (from, to),
+		(Err(err), _) | (_, Err(err)) => {
+			error!("Invalid email address: {}", err);
+
+			return false;
+		}
+	};
+
+	let message: Message = match Message::builder().from(from).to(to.clone()).subject(subject).body(String::from(body)) {
+		Ok(message) => message,
+		Err(err) => {
+			error!("{:?}", err);
+
+			return false;
+		}
+	};
+
+	let tls: TlsParameters = match TlsParameters::new(config.smtp_server.clone()) {
+		Ok(tls) => tls,
+		Err(err) => {
+			error!("{:?}", err);
+
+			return false;
+		}
+	};
+
+	let mailer: AsyncSmtpTransport<Tokio1Executor> = match AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_server) {
+		Ok(mailer) => mailer
+			.port(config.smtp_port)
+			.tls(Tls::Required(tls))
+			.credentials(Credentials::new(config.user, config.password))
+			.build(),
+		Err(err) => {
+			error!("{:?}", err);
+
+			return false;
+		}
+	};
 
 	match mailer.send(message).await {
-		Ok(mailer_response) => {
-			email.response = format!("{:?}", mailer_response);
-			email.result = true;
+		Ok(response) => {
+			info!("Email to {} was sent: {:?}", to, response.code());
+
+			true
+		}
+		Err(err) => {
+			error!("{:?}", err);
+
+			false
 		}
-		Err(err) => email.response = format!("{:?}", err),
 	}
 }
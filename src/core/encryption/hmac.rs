@@ -0,0 +1,57 @@
+use hmac::{Hmac, Mac};
+use log::error;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub fn digest(body: &[u8]) -> String {
+	hex::encode(Sha256::digest(body))
+}
+
+pub fn generate_secret() -> String {
+	hex::encode(rand::thread_rng().r#gen::<[u8; 32]>())
+}
+
+pub fn get_message(method: &str, path: &str, query: &str, timestamp: &str, nonce: &str, digest: &str) -> String {
+	format!("{}\n{}\n{}\n{}\n{}\n{}", method.to_uppercase(), path, query, timestamp, nonce, digest)
+}
+
+pub fn is_equal(left: &str, right: &str) -> bool {
+	left.len() == right.len() && left.bytes().zip(right.bytes()).fold(0u8, |result, (left, right)| result | (left ^ right)) == 0
+}
+
+pub fn sign(message: &str, secret: &str) -> String {
+	match HmacSha256::new_from_slice(secret.as_bytes()) {
+		Ok(mut mac) => {
+			mac.update(message.as_bytes());
+
+			hex::encode(mac.finalize().into_bytes())
+		}
+		Err(err) => {
+			error!("Failed to load HMAC secret. {:?}", err);
+
+			String::new()
+		}
+	}
+}
+
+pub fn verify(message: &str, secret: &str, signature: &str) -> bool {
+	let signature: Vec<u8> = match hex::decode(signature) {
+		Ok(signature) => signature,
+		Err(_) => return false,
+	};
+
+	match HmacSha256::new_from_slice(secret.as_bytes()) {
+		Ok(mut mac) => {
+			mac.update(message.as_bytes());
+
+			mac.verify_slice(&signature).is_ok()
+		}
+		Err(err) => {
+			error!("Failed to load HMAC secret. {:?}", err);
+
+			false
+		}
+	}
+}
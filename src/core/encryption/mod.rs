@@ -1 +1,4 @@
+pub mod hmac;
+pub mod password;
 pub mod rsa;
+pub mod totp;
@@ -0,0 +1,41 @@
+use crate::core::encryption::hmac::is_equal;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use log::error;
+
+pub fn hash_password(password: &str) -> String {
+	let salt: SaltString = SaltString::generate(&mut OsRng);
+
+	match Argon2::default().hash_password(password.as_bytes(), &salt) {
+		Ok(hash) => hash.to_string(),
+		Err(err) => {
+			error!("Failed to hash password. {:?}", err);
+
+			String::new()
+		}
+	}
+}
+
+pub fn is_hashed(hash: &str) -> bool {
+	hash.starts_with("$argon2id$")
+}
+
+pub fn verify_password(password: &str, hash: &str) -> bool {
+	if hash.is_empty() {
+		return false;
+	}
+
+	if !is_hashed(hash) {
+		return is_equal(password, hash);
+	}
+
+	match PasswordHash::new(hash) {
+		Ok(hash) => Argon2::default().verify_password(password.as_bytes(), &hash).is_ok(),
+		Err(err) => {
+			error!("Failed to parse password hash. {:?}", err);
+
+			false
+		}
+	}
+}
@@ -1,5 +1,7 @@
 use base64::engine::general_purpose;
 use base64::Engine;
+use crate::core::config::models::AppConfig;
+use log::error;
 use rand::rngs::OsRng;
 use rsa::pkcs1::{
 	DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey,
@@ -7,12 +9,17 @@ use rsa::pkcs1::{
 use rsa::pkcs8::der::zeroize::Zeroizing;
 use rsa::pkcs8::LineEnding;
 use rsa::{Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey};
-use std::fs::{read_to_string, File};
-use std::io::Write;
+use std::fs::read_to_string;
 
-pub fn decrypt(text: &str, client_key: &str) -> String {
-	let file: String = read_to_string(&format!("clients/{}/private-key.pem", client_key)).unwrap_or_default();
-	let private_key: RsaPrivateKey = RsaPrivateKey::from_pkcs1_pem(&file).expect("Failed to load RSA private key.");
+pub fn decrypt(text: &str, private_key: &str) -> String {
+	let private_key: RsaPrivateKey = match RsaPrivateKey::from_pkcs1_pem(private_key) {
+		Ok(private_key) => private_key,
+		Err(err) => {
+			error!("Failed to load RSA private key. {:?}", err);
+
+			return String::new();
+		}
+	};
 
 	let decoded: Vec<u8> = general_purpose::STANDARD.decode(text).unwrap_or_default();
 	let decrypted: Vec<u8> = private_key.decrypt(Pkcs1v15Encrypt, &decoded).unwrap_or_default();
@@ -20,25 +27,44 @@ pub fn decrypt(text: &str, client_key: &str) -> String {
 	String::from_utf8_lossy(&decrypted).to_string()
 }
 
-pub fn encrypt(text: &str, client_key: &str) -> String {
-	let file: String = read_to_string(&format!("clients/{}/public-key.pem", client_key)).unwrap_or_default();
-	let public_key: RsaPublicKey = RsaPublicKey::from_pkcs1_pem(&file).expect("Failed to load RSA public key.");
+pub fn encrypt(text: &str, public_key: &str) -> String {
+	let public_key: RsaPublicKey = match RsaPublicKey::from_pkcs1_pem(public_key) {
+		Ok(public_key) => public_key,
+		Err(err) => {
+			error!("Failed to load RSA public key. {:?}", err);
+
+			return String::new();
+		}
+	};
 
 	let encrypted: Vec<u8> = public_key.encrypt(&mut OsRng, Pkcs1v15Encrypt, text.as_bytes()).unwrap_or_default();
 
 	general_purpose::STANDARD.encode(encrypted)
 }
 
-pub fn generate_key(size: &usize) {
-	let private_key: RsaPrivateKey = RsaPrivateKey::new(&mut OsRng, *size).expect("Failed to generate private key");
-	let pem: Zeroizing<String> = private_key.to_pkcs1_pem(LineEnding::LF).unwrap_or_default();
+pub fn get_client_path(client_key: &str, file: &str) -> String {
+	let directory: String = AppConfig::instance().client.directory;
+
+	format!("{}/{}/{}", directory, client_key, file)
+}
+
+pub fn read_client_key(client_key: &str, file: &str) -> String {
+	read_to_string(get_client_path(client_key, file)).unwrap_or_default()
+}
+
+pub fn generate_key(size: &usize) -> Option<(String, String)> {
+	let private_key: RsaPrivateKey = match RsaPrivateKey::new(&mut OsRng, *size) {
+		Ok(private_key) => private_key,
+		Err(err) => {
+			error!("Failed to generate private key. {:?}", err);
 
-	let mut file: File = File::create("../../../outputs/rsa-private-key.pem").expect("Failed to create RSA private key file.");
-	file.write_all(pem.as_bytes()).expect("Failed to write RSA private key file.");
+			return None;
+		}
+	};
+	let private_pem: Zeroizing<String> = private_key.to_pkcs1_pem(LineEnding::LF).unwrap_or_default();
 
 	let public_key: RsaPublicKey = RsaPublicKey::from(&private_key);
-	let string: String = public_key.to_pkcs1_pem(LineEnding::LF).unwrap_or_default().to_string();
+	let public_pem: String = public_key.to_pkcs1_pem(LineEnding::LF).unwrap_or_default();
 
-	file = File::create("../../../outputs/rsa-public-key.pem").expect("Failed to create RSA public key file.");
-	file.write_all(string.as_bytes()).expect("Failed to write RSA public key file.");
+	Some((private_pem.to_string(), public_pem))
 }
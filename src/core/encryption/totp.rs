@@ -0,0 +1,67 @@
+use chrono::Utc;
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sha1::Sha1;
+
+type HmacSha1 = Hmac<Sha1>;
+
+const DIGITS: u32 = 6;
+const PERIOD: i64 = 30;
+
+pub fn generate_secret() -> String {
+	BASE32_NOPAD.encode(&rand::thread_rng().r#gen::<[u8; 20]>())
+}
+
+pub fn generate_code(secret: &str, step: i64) -> String {
+	let key: Vec<u8> = BASE32_NOPAD.decode(secret.as_bytes()).unwrap_or_default();
+
+	let mut mac: HmacSha1 = match HmacSha1::new_from_slice(&key) {
+		Ok(mac) => mac,
+		Err(_) => return String::new(),
+	};
+	mac.update(&step.to_be_bytes());
+
+	let hash: Vec<u8> = mac.finalize().into_bytes().to_vec();
+	let offset: usize = (hash[hash.len() - 1] & 0x0f) as usize;
+	let binary: u32 = u32::from_be_bytes([hash[offset] & 0x7f, hash[offset + 1], hash[offset + 2], hash[offset + 3]]);
+
+	format!("{:0width$}", binary % 10u32.pow(DIGITS), width = DIGITS as usize)
+}
+
+pub fn get_step() -> i64 {
+	Utc::now().timestamp() / PERIOD
+}
+
+pub fn get_uri(issuer: &str, account: &str, secret: &str) -> String {
+	format!(
+		"otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+		encode(issuer),
+		encode(account),
+		secret,
+		encode(issuer),
+		DIGITS,
+		PERIOD
+	)
+}
+
+pub fn verify_code(secret: &str, code: &str, last: i64) -> Option<i64> {
+	let code: &str = code.trim();
+
+	if code.len() != DIGITS as usize || !code.chars().all(|character| character.is_ascii_digit()) {
+		return None;
+	}
+
+	let step: i64 = get_step();
+
+	(step - 1..=step + 1).filter(|step| *step > last).find(|step| generate_code(secret, *step) == code)
+}
+
+fn encode(text: &str) -> String {
+	text.bytes()
+		.map(|byte| match byte {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+			_ => format!("%{:02X}", byte),
+		})
+		.collect()
+}
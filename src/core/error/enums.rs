@@ -0,0 +1,95 @@
+use crate::core::authentication::enums::AuthenticationError;
+use crate::core::base::models::BaseResponse;
+use crate::integration::enums::IntegrationError;
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum AppError {
+	Conflict(String),
+	Forbidden(String),
+	Internal(String),
+	NotFound(String),
+	Unauthorized(String),
+	Upstream(String),
+	Validation(String),
+}
+
+impl Display for AppError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+		match self {
+			AppError::Internal(_) => write!(formatter, "Internal server error."),
+			AppError::Conflict(message)
+			| AppError::Forbidden(message)
+			| AppError::NotFound(message)
+			| AppError::Unauthorized(message)
+			| AppError::Upstream(message)
+			| AppError::Validation(message) => write!(formatter, "{}", message),
+		}
+	}
+}
+
+impl ResponseError for AppError {
+	fn status_code(&self) -> StatusCode {
+		match self {
+			AppError::Conflict(_) => StatusCode::CONFLICT,
+			AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+			AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+			AppError::NotFound(_) => StatusCode::NOT_FOUND,
+			AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+			AppError::Upstream(_) => StatusCode::BAD_GATEWAY,
+			AppError::Validation(_) => StatusCode::BAD_REQUEST,
+		}
+	}
+
+	fn error_response(&self) -> HttpResponse {
+		if let AppError::Internal(detail) = self {
+			error!("{}", detail);
+		}
+
+		HttpResponse::build(self.status_code()).json(BaseResponse {
+			response: self.to_string(),
+			result: false,
+		})
+	}
+}
+
+impl From<AuthenticationError> for AppError {
+	fn from(err: AuthenticationError) -> Self {
+		match err.status_code() {
+			StatusCode::BAD_REQUEST | StatusCode::PAYLOAD_TOO_LARGE => AppError::Validation(err.to_string()),
+			StatusCode::FORBIDDEN => AppError::Forbidden(err.to_string()),
+			_ => AppError::Unauthorized(err.to_string()),
+		}
+	}
+}
+
+impl From<IntegrationError> for AppError {
+	fn from(err: IntegrationError) -> Self {
+		warn!("{}", err);
+
+		match err {
+			IntegrationError::CircuitOpen { .. } | IntegrationError::Network(_) | IntegrationError::RateLimit { .. } => {
+				AppError::Upstream("Upstream service is unavailable.".to_string())
+			}
+			IntegrationError::Cassette(_) | IntegrationError::Parse { .. } | IntegrationError::Status { .. } => {
+				AppError::Upstream("Upstream service returned an invalid response.".to_string())
+			}
+		}
+	}
+}
+
+impl From<mongodb::error::Error> for AppError {
+	fn from(err: mongodb::error::Error) -> Self {
+		AppError::Internal(format!("{:?}", err))
+	}
+}
+
+impl From<tera::Error> for AppError {
+	fn from(err: tera::Error) -> Self {
+		AppError::Internal(format!("{:?}", err))
+	}
+}
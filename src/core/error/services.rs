@@ -0,0 +1,13 @@
+use crate::core::error::enums::AppError;
+use actix_web::error::JsonPayloadError;
+use actix_web::{Error, HttpRequest};
+
+pub fn json_error_handler(err: JsonPayloadError, _request: &HttpRequest) -> Error {
+	let error: AppError = match err {
+		JsonPayloadError::ContentType => AppError::Validation("Content type must be application/json.".to_string()),
+		JsonPayloadError::Overflow { .. } | JsonPayloadError::OverflowKnownLength { .. } => AppError::Validation("Request body is too large.".to_string()),
+		err => AppError::Validation(format!("Invalid request body. {}", err)),
+	};
+
+	error.into()
+}
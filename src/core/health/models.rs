@@ -0,0 +1,63 @@
+use crate::core::base::enums::Status;
+use crate::trading::asset::enums::Provider;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthAsset {
+	pub error: Option<String>,
+	pub last: DateTime<Utc>,
+	pub provider: Provider,
+	pub status: Status,
+	pub synced: Option<DateTime<Utc>>,
+	pub synchronized: bool,
+	pub ticker: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthCheck {
+	pub detail: String,
+	pub healthy: bool,
+	pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthDiagnosticsRequest {
+	pub authentication: Option<String>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthDiagnosticsResponse {
+	pub assets: Vec<HealthAsset>,
+	pub errors: Vec<HealthError>,
+	pub jobs: Vec<HealthJob>,
+	pub response: String,
+	pub result: bool,
+	pub uptime: i64,
+	pub version: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthError {
+	pub message: String,
+	pub target: String,
+	pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthJob {
+	pub duration: Option<i64>,
+	pub last: Option<DateTime<Utc>>,
+	pub name: String,
+	pub running: bool,
+	pub schedule: String,
+	pub started: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HealthReadyResponse {
+	pub checks: Vec<HealthCheck>,
+	pub response: String,
+	pub result: bool,
+}
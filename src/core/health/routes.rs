@@ -0,0 +1,10 @@
+use crate::core::health::services::diagnostics;
+use actix_web::{web, HttpResponse};
+
+pub fn config(config: &mut web::ServiceConfig) {
+	config.service(
+		web::resource("").route(web::post().to(diagnostics)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/").route(web::post().to(diagnostics)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	);
+}
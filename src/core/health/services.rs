@@ -0,0 +1,294 @@
+use crate::core::authentication::models::AuthenticatedUser;
+use crate::core::base::enums::Status;
+use crate::core::base::models::BaseResponse;
+use crate::core::config::models::AppConfig;
+use crate::core::error::enums::AppError;
+use crate::core::health::models::{
+	HealthAsset, HealthCheck, HealthDiagnosticsRequest, HealthDiagnosticsResponse, HealthError, HealthJob, HealthReadyResponse,
+};
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
+use crate::integration::discord::services::Discord;
+use crate::trading::asset::models::Asset;
+use crate::trading::asset::repositories::find;
+use actix_web::{web, HttpResponse, Responder};
+use bson::doc;
+use chrono::{DateTime, Utc};
+use mongodb::Database;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+
+pub struct HealthMonitor {
+	pub errors: Mutex<VecDeque<HealthError>>,
+	pub jobs: Mutex<HashMap<String, HealthJob>>,
+	pub started: DateTime<Utc>,
+	pub syncs: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl HealthMonitor {
+	fn new() -> Self {
+		HealthMonitor {
+			errors: Mutex::new(VecDeque::new()),
+			jobs: Mutex::new(HashMap::new()),
+			started: Utc::now(),
+			syncs: Mutex::new(HashMap::new()),
+		}
+	}
+
+	pub fn instance() -> &'static Self {
+		static INSTANCE: OnceLock<HealthMonitor> = OnceLock::new();
+		INSTANCE.get_or_init(HealthMonitor::new)
+	}
+
+	pub fn get_errors(&self) -> Vec<HealthError> {
+		match self.errors.lock() {
+			Ok(errors) => errors.iter().rev().cloned().collect(),
+			Err(_) => Vec::new(),
+		}
+	}
+
+	pub fn get_jobs(&self) -> Vec<HealthJob> {
+		let mut jobs: Vec<HealthJob> = match self.jobs.lock() {
+			Ok(jobs) => jobs.values().cloned().collect(),
+			Err(_) => Vec::new(),
+		};
+		jobs.sort_by(|a, b| a.name.cmp(&b.name));
+
+		jobs
+	}
+
+	pub fn get_syncs(&self) -> HashMap<String, DateTime<Utc>> {
+		match self.syncs.lock() {
+			Ok(syncs) => syncs.clone(),
+			Err(_) => HashMap::new(),
+		}
+	}
+
+	pub fn get_uptime(&self) -> i64 {
+		Utc::now().signed_duration_since(self.started).num_seconds()
+	}
+
+	pub fn record_error(&self, target: &str, message: &str) {
+		let limit: usize = AppConfig::instance().health.error_history;
+
+		if let Ok(mut errors) = self.errors.lock() {
+			errors.push_back(HealthError {
+				message: message.to_string(),
+				target: target.to_string(),
+				timestamp: Utc::now(),
+			});
+
+			while errors.len() > limit {
+				errors.pop_front();
+			}
+		}
+	}
+
+	pub fn record_sync(&self, ticker: &str) {
+		if let Ok(mut syncs) = self.syncs.lock() {
+			syncs.insert(ticker.to_string(), Utc::now());
+		}
+	}
+
+	pub fn start_job(&self, name: &str, schedule: &str) {
+		if let Ok(mut jobs) = self.jobs.lock() {
+			jobs.insert(
+				name.to_string(),
+				HealthJob {
+					name: name.to_string(),
+					running: true,
+					schedule: schedule.to_string(),
+					started: Utc::now(),
+					..Default::default()
+				},
+			);
+		}
+	}
+
+	pub fn finish_job(&self, name: &str, started: DateTime<Utc>) {
+		if let Ok(mut jobs) = self.jobs.lock()
+			&& let Some(job) = jobs.get_mut(name)
+		{
+			job.duration = Some(Utc::now().signed_duration_since(started).num_milliseconds());
+			job.last = Some(started);
+		}
+
+		Metrics::instance().observe(
+			&Metric::SchedulerJobDuration,
+			&[("job", name)],
+			Utc::now().signed_duration_since(started).num_milliseconds() as f64 / 1000.0,
+		);
+	}
+
+	pub fn stop_job(&self, name: &str) {
+		if let Ok(mut jobs) = self.jobs.lock()
+			&& let Some(job) = jobs.get_mut(name)
+		{
+			job.running = false;
+		}
+	}
+}
+
+pub fn get_discord_check(configured: bool, connected: bool) -> HealthCheck {
+	HealthCheck {
+		detail: match (configured, connected) {
+			(false, _) => "Discord is not configured.".to_string(),
+			(true, true) => "Discord gateway is connected.".to_string(),
+			(true, false) => "Discord gateway is not connected.".to_string(),
+		},
+		healthy: !configured || connected,
+		name: "discord".to_string(),
+	}
+}
+
+pub fn get_scheduler_check(jobs: &[HealthJob], max_age: i64) -> HealthCheck {
+	let now: DateTime<Utc> = Utc::now();
+	let running: Vec<&HealthJob> = jobs.iter().filter(|job| job.running).collect();
+	let stale: Vec<&str> = running
+		.iter()
+		.filter(|job| now.signed_duration_since(job.last.unwrap_or(job.started)).num_seconds() > max_age)
+		.map(|job| job.name.as_str())
+		.collect();
+
+	HealthCheck {
+		detail: if running.is_empty() {
+			"No scheduler is running.".to_string()
+		} else if stale.is_empty() {
+			format!("{} scheduler jobs are running.", running.len())
+		} else {
+			format!("Scheduler jobs have not run for over {} seconds: {}.", max_age, stale.join(", "))
+		},
+		healthy: !running.is_empty() && stale.is_empty(),
+		name: "scheduler".to_string(),
+	}
+}
+
+pub fn get_sync_check(
+	assets: &[Asset],
+	syncs: &HashMap<String, DateTime<Utc>>,
+	started: DateTime<Utc>,
+	max_age: i64,
+) -> HealthCheck {
+	let tickers: Vec<&str> = assets
+		.iter()
+		.filter(|asset| asset.status == Status::Active && asset.sync.synchronized)
+		.map(|asset| asset.ticker.as_str())
+		.collect();
+	let last: Option<DateTime<Utc>> = tickers.iter().filter_map(|ticker| syncs.get(*ticker).copied()).max();
+
+	match (tickers.is_empty(), last) {
+		(true, _) => HealthCheck {
+			detail: "No synchronized asset.".to_string(),
+			healthy: true,
+			name: "sync".to_string(),
+		},
+		(false, Some(last)) => {
+			let age: i64 = Utc::now().signed_duration_since(last).num_seconds();
+
+			HealthCheck {
+				detail: format!("Last vendor sync was {} seconds ago.", age),
+				healthy: age <= max_age,
+				name: "sync".to_string(),
+			}
+		}
+		(false, None) => {
+			let age: i64 = Utc::now().signed_duration_since(started).num_seconds();
+
+			HealthCheck {
+				detail: format!("No vendor sync since startup {} seconds ago.", age),
+				healthy: age <= max_age,
+				name: "sync".to_string(),
+			}
+		}
+	}
+}
+
+async fn get_database_check(database: &Database) -> HealthCheck {
+	match database.run_command(doc! {"ping": 1}).await {
+		Ok(_) => HealthCheck {
+			detail: "MongoDB is reachable.".to_string(),
+			healthy: true,
+			name: "database".to_string(),
+		},
+		Err(err) => HealthCheck {
+			detail: format!("MongoDB is unreachable. {}", err),
+			healthy: false,
+			name: "database".to_string(),
+		},
+	}
+}
+
+pub async fn health() -> impl Responder {
+	HttpResponse::Ok().json(BaseResponse {
+		response: "Service is alive.".to_string(),
+		result: true,
+	})
+}
+
+pub async fn ready(database: web::Data<Database>) -> impl Responder {
+	let database_check: HealthCheck = get_database_check(database.get_ref()).await;
+	let sync_check: HealthCheck = if database_check.healthy {
+		get_sync_check(
+			&find(database.get_ref()).await.unwrap_or_default(),
+			&HealthMonitor::instance().get_syncs(),
+			HealthMonitor::instance().started,
+			AppConfig::instance().health.sync_max_age,
+		)
+	} else {
+		HealthCheck {
+			detail: "MongoDB is unreachable.".to_string(),
+			healthy: false,
+			name: "sync".to_string(),
+		}
+	};
+
+	let checks: Vec<HealthCheck> = vec![
+		database_check,
+		get_discord_check(!AppConfig::instance().discord.token.is_empty(), Discord::instance().is_connected()),
+		get_scheduler_check(&HealthMonitor::instance().get_jobs(), AppConfig::instance().health.scheduler_max_age),
+		sync_check,
+	];
+
+	let result: bool = checks.iter().all(|check| check.healthy);
+	let response: HealthReadyResponse = HealthReadyResponse {
+		checks,
+		response: if result { "Service is ready." } else { "Service is not ready." }.to_string(),
+		result,
+	};
+
+	if result {
+		HttpResponse::Ok().json(response)
+	} else {
+		HttpResponse::ServiceUnavailable().json(response)
+	}
+}
+
+pub async fn diagnostics(
+	_user: AuthenticatedUser,
+	database: web::Data<Database>,
+	_data: web::Json<HealthDiagnosticsRequest>,
+) -> Result<HttpResponse, AppError> {
+	let assets: Vec<Asset> = find(database.get_ref()).await.ok_or(AppError::Internal("Failed to find assets.".to_string()))?;
+	let syncs: HashMap<String, DateTime<Utc>> = HealthMonitor::instance().get_syncs();
+
+	Ok(HttpResponse::Ok().json(HealthDiagnosticsResponse {
+		assets: assets
+			.into_iter()
+			.map(|asset| HealthAsset {
+				error: asset.sync.error,
+				last: asset.sync.last,
+				provider: asset.provider,
+				status: asset.status,
+				synced: syncs.get(&asset.ticker).copied(),
+				synchronized: asset.sync.synchronized,
+				ticker: asset.ticker,
+			})
+			.collect(),
+		errors: HealthMonitor::instance().get_errors(),
+		jobs: HealthMonitor::instance().get_jobs(),
+		response: "Diagnostics retrieved successfully.".to_string(),
+		result: true,
+		uptime: HealthMonitor::instance().get_uptime(),
+		version: env!("CARGO_PKG_VERSION").to_string(),
+	}))
+}
@@ -0,0 +1,71 @@
+use crate::core::authorization::enums::Permission;
+use crate::trading::asset::models::Asset;
+use crate::user::models::User;
+use bson::{from_document, Document};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Serialize)]
+pub enum HistoryEntity {
+	TradingAsset,
+	#[default]
+	User,
+}
+
+impl HistoryEntity {
+	pub fn collection(&self) -> &'static str {
+		match self {
+			HistoryEntity::TradingAsset => "trading_asset",
+			HistoryEntity::User => "user",
+		}
+	}
+
+	pub fn log_collection(&self) -> &'static str {
+		match self {
+			HistoryEntity::TradingAsset => "trading_asset_log_data",
+			HistoryEntity::User => "user_log_data",
+		}
+	}
+
+	pub fn log_key(&self) -> &'static str {
+		match self {
+			HistoryEntity::TradingAsset => "trading_asset_id",
+			HistoryEntity::User => "user_id",
+		}
+	}
+
+	pub fn hidden(&self) -> &'static [&'static str] {
+		match self {
+			HistoryEntity::TradingAsset => &[],
+			HistoryEntity::User => &["password", "totp"],
+		}
+	}
+
+	pub fn protected(&self) -> &'static [&'static str] {
+		match self {
+			HistoryEntity::TradingAsset => &[],
+			HistoryEntity::User => &["password", "status", "totp", "type"],
+		}
+	}
+
+	pub fn unique_key(&self) -> &'static str {
+		match self {
+			HistoryEntity::TradingAsset => "name",
+			HistoryEntity::User => "username",
+		}
+	}
+
+	pub fn permission(&self) -> Permission {
+		match self {
+			HistoryEntity::TradingAsset => Permission::ManageAssets,
+			HistoryEntity::User => Permission::ManageUsers,
+		}
+	}
+
+	pub fn is_valid(&self, document: &Document) -> bool {
+		match self {
+			HistoryEntity::TradingAsset => from_document::<Asset>(document.clone()).is_ok(),
+			HistoryEntity::User => from_document::<User>(document.clone()).is_ok(),
+		}
+	}
+}
@@ -0,0 +1,65 @@
+use crate::core::database::models::Timestamp;
+use crate::core::history::enums::HistoryEntity;
+use bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HistoryChange {
+	pub field: String,
+	pub from: Value,
+	pub to: Value,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HistoryDiffRequest {
+	pub authentication: Option<String>,
+	pub entity: HistoryEntity,
+	pub from: ObjectId,
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	pub to: Option<ObjectId>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HistoryDiffResponse {
+	pub data: Vec<HistoryChange>,
+	pub modified: Option<Timestamp>,
+	pub response: String,
+	pub result: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HistoryListRequest {
+	pub authentication: Option<String>,
+	pub entity: HistoryEntity,
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HistoryListResponse {
+	pub data: Vec<HistoryVersion>,
+	pub response: String,
+	pub result: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HistoryRestoreRequest {
+	pub authentication: Option<String>,
+	pub entity: HistoryEntity,
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	pub token: Option<String>,
+	pub version: ObjectId,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HistoryVersion {
+	pub archived: Option<Timestamp>,
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	pub modified: Timestamp,
+}
@@ -0,0 +1,51 @@
+use bson::oid::ObjectId;
+use bson::{doc, Document};
+use log::error;
+use mongodb::error::Error;
+use mongodb::results::{InsertOneResult, UpdateResult};
+use mongodb::Database;
+use serenity::futures::TryStreamExt;
+
+pub async fn find_by_key(database: &Database, collection: &str, key: &str, id: &ObjectId) -> Option<Vec<Document>> {
+	match database
+		.collection::<Document>(collection)
+		.find(doc! {key: id})
+		.sort(doc! {"modified.timestamp": -1})
+		.limit(100)
+		.await
+	{
+		Ok(cursor) => match cursor.try_collect().await {
+			Ok(document) => Some(document),
+			Err(err) => {
+				error!("{:?}", err);
+				None
+			}
+		},
+		Err(err) => {
+			error!("{:?}", err);
+			None
+		}
+	}
+}
+
+pub async fn find_one_by_id(database: &Database, collection: &str, id: &ObjectId) -> Option<Document> {
+	database.collection::<Document>(collection).find_one(doc! {"_id": id}).await.unwrap_or_else(|err| {
+		error!("{:?}", err);
+		None
+	})
+}
+
+pub async fn find_one_by_key(database: &Database, collection: &str, key: &str, id: &ObjectId, version: &ObjectId) -> Option<Document> {
+	database.collection::<Document>(collection).find_one(doc! {"_id": version, key: id}).await.unwrap_or_else(|err| {
+		error!("{:?}", err);
+		None
+	})
+}
+
+pub async fn insert_one(database: &Database, collection: &str, document: Document) -> Result<InsertOneResult, Error> {
+	database.collection::<Document>(collection).insert_one(document).await
+}
+
+pub async fn replace_one(database: &Database, collection: &str, id: &ObjectId, document: Document) -> Result<UpdateResult, Error> {
+	database.collection::<Document>(collection).replace_one(doc! {"_id": id}, document).await
+}
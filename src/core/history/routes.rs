@@ -0,0 +1,18 @@
+use crate::core::history::services::{diff, list, restore};
+use actix_web::{web, HttpResponse};
+
+pub fn config(config: &mut web::ServiceConfig) {
+	config.service(
+		web::resource("").route(web::post().to(list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/").route(web::post().to(list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/diff").route(web::post().to(diff)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/diff/").route(web::post().to(diff)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/restore").route(web::post().to(restore)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/restore/").route(web::post().to(restore)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	);
+}
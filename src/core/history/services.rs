@@ -0,0 +1,195 @@
+use crate::core::authentication::models::AuthenticatedUser;
+use crate::core::authorization::enums::Permission;
+use crate::core::authorization::services::{authorize, has_permission};
+use crate::core::base::models::BaseResponse;
+use crate::core::database::models::Timestamp;
+use crate::core::database::services::{archive, database_error, timestamp};
+use crate::core::error::enums::AppError;
+use crate::core::history::enums::HistoryEntity;
+use crate::core::history::models::{
+	HistoryChange, HistoryDiffRequest, HistoryDiffResponse, HistoryListRequest, HistoryListResponse, HistoryRestoreRequest,
+	HistoryVersion,
+};
+use crate::core::history::repositories::{find_by_key, find_one_by_id, find_one_by_key, insert_one, replace_one};
+use crate::user::log::models::UserLog;
+use crate::user::models::User;
+use actix_web::{web, HttpResponse};
+use bson::{from_bson, Bson, Document};
+use log::info;
+use mongodb::Database;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+fn flatten(document: &Document, prefix: &str, fields: &mut BTreeMap<String, Bson>) {
+	for (key, value) in document {
+		let field: String = if prefix.is_empty() {
+			key.clone()
+		} else {
+			format!("{}.{}", prefix, key)
+		};
+
+		match value {
+			Bson::Document(document) => flatten(document, &field, fields),
+			value => {
+				fields.insert(field, value.clone());
+			}
+		}
+	}
+}
+
+fn is_ignored(entity: &HistoryEntity, field: &str) -> bool {
+	let root: &str = field.split('.').next().unwrap_or_default();
+
+	["_id", "archived", "created", "modified", entity.log_key()].contains(&root) || entity.hidden().contains(&root)
+}
+
+pub fn get_diff(entity: &HistoryEntity, from: &Document, to: &Document) -> Vec<HistoryChange> {
+	let mut from_fields: BTreeMap<String, Bson> = BTreeMap::new();
+	let mut to_fields: BTreeMap<String, Bson> = BTreeMap::new();
+	flatten(from, "", &mut from_fields);
+	flatten(to, "", &mut to_fields);
+
+	let mut fields: Vec<&String> = from_fields.keys().chain(to_fields.keys()).filter(|field| !is_ignored(entity, field)).collect();
+	fields.sort();
+	fields.dedup();
+
+	fields
+		.into_iter()
+		.filter(|field| from_fields.get(*field) != to_fields.get(*field))
+		.map(|field| HistoryChange {
+			field: field.clone(),
+			from: from_fields.get(field).cloned().map(Bson::into_relaxed_extjson).unwrap_or(Value::Null),
+			to: to_fields.get(field).cloned().map(Bson::into_relaxed_extjson).unwrap_or(Value::Null),
+		})
+		.collect()
+}
+
+pub fn get_restore_document(entity: &HistoryEntity, version: &Document, current: &Document) -> Document {
+	let mut document: Document = version.clone();
+	document.remove("archived");
+	document.remove(entity.log_key());
+
+	for field in ["_id", "created"].iter().chain(entity.protected()) {
+		match current.get(*field) {
+			Some(value) => {
+				document.insert(*field, value.clone());
+			}
+			None => {
+				document.remove(*field);
+			}
+		}
+	}
+
+	document
+}
+
+fn get_timestamp(document: &Document, key: &str) -> Option<Timestamp> {
+	document.get(key).cloned().and_then(|modified| from_bson(modified).ok())
+}
+
+async fn authorize_history(database: &Database, user: &AuthenticatedUser, entity: &HistoryEntity) -> Result<User, AppError> {
+	let (_, user): (UserLog, User) = authorize(Some(database), &user.log.authentication, &entity.permission()).await?;
+
+	Ok(user)
+}
+
+pub async fn list(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<HistoryListRequest>,
+) -> Result<HttpResponse, AppError> {
+	authorize_history(database.get_ref(), &user, &data.entity).await?;
+
+	let versions: Vec<Document> = find_by_key(database.get_ref(), data.entity.log_collection(), data.entity.log_key(), &data.id)
+		.await
+		.ok_or(AppError::Internal("Failed to find history.".to_string()))?;
+
+	Ok(HttpResponse::Ok().json(HistoryListResponse {
+		data: versions
+			.iter()
+			.filter_map(|version| {
+				Some(HistoryVersion {
+					archived: get_timestamp(version, "archived"),
+					id: version.get_object_id("_id").ok()?,
+					modified: get_timestamp(version, "modified").unwrap_or_default(),
+				})
+			})
+			.collect(),
+		response: "History retrieved successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn diff(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<HistoryDiffRequest>,
+) -> Result<HttpResponse, AppError> {
+	let entity: HistoryEntity = data.entity;
+
+	authorize_history(database.get_ref(), &user, &entity).await?;
+
+	let from: Option<Document> = find_one_by_key(database.get_ref(), entity.log_collection(), entity.log_key(), &data.id, &data.from).await;
+	let to: Option<Document> = match &data.to {
+		Some(to) => find_one_by_key(database.get_ref(), entity.log_collection(), entity.log_key(), &data.id, to).await,
+		None => find_one_by_id(database.get_ref(), entity.collection(), &data.id).await,
+	};
+
+	match (from, to) {
+		(Some(from), Some(to)) => Ok(HttpResponse::Ok().json(HistoryDiffResponse {
+			data: get_diff(&entity, &from, &to),
+			modified: get_timestamp(&to, "modified"),
+			response: "History difference retrieved successfully.".to_string(),
+			result: true,
+		})),
+		_ => Err(AppError::NotFound("Version not found.".to_string())),
+	}
+}
+
+pub async fn restore(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<HistoryRestoreRequest>,
+) -> Result<HttpResponse, AppError> {
+	let entity: HistoryEntity = data.entity;
+	let actor: User = authorize_history(database.get_ref(), &user, &entity).await?;
+
+	let (version, current): (Document, Document) = match (
+		find_one_by_key(database.get_ref(), entity.log_collection(), entity.log_key(), &data.id, &data.version).await,
+		find_one_by_id(database.get_ref(), entity.collection(), &data.id).await,
+	) {
+		(Some(version), Some(current)) => (version, current),
+		_ => return Err(AppError::NotFound("Version not found.".to_string())),
+	};
+
+	if entity == HistoryEntity::User
+		&& current.get_str("type").unwrap_or_default() != "Member"
+		&& !has_permission(&actor._type, &Permission::ManageRoles)
+	{
+		return Err(AppError::Forbidden("Access denied.".to_string()));
+	}
+
+	let document: Document = get_restore_document(&entity, &version, &current);
+
+	if !entity.is_valid(&document) {
+		return Err(AppError::Conflict("Version is no longer compatible.".to_string()));
+	}
+
+	insert_one(
+		database.get_ref(),
+		entity.log_collection(),
+		archive(&current, Some(user.log.user.clone()), entity.log_key()),
+	)
+	.await?;
+
+	replace_one(database.get_ref(), entity.collection(), &data.id, timestamp(&document, Some(user.log.user.clone()), &true, None))
+		.await
+		.map_err(|err| database_error(err, entity.unique_key()))?;
+
+	info!("{:?} {} was restored to version {} by {}.", entity, data.id, data.version, user.log.user.username);
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Version has been restored successfully.".to_string(),
+		result: true,
+	}))
+}
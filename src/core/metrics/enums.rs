@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, Deserialize, EnumIter, Eq, Hash, PartialEq, Serialize)]
+pub enum Metric {
+	AuthenticationFailures,
+	DatabaseOperationDuration,
+	DiscordMessagesSent,
+	HttpRequestDuration,
+	HttpRequests,
+	IntegrationErrors,
+	IntegrationRequestDuration,
+	IntegrationRequests,
+	SchedulerJobDuration,
+	TradingCandlesInserted,
+}
+
+impl Metric {
+	pub fn get_help(&self) -> &'static str {
+		match self {
+			Metric::AuthenticationFailures => "Authentication failures by reason.",
+			Metric::DatabaseOperationDuration => "MongoDB command duration in seconds.",
+			Metric::DiscordMessagesSent => "Discord messages sent.",
+			Metric::HttpRequestDuration => "HTTP request duration in seconds.",
+			Metric::HttpRequests => "HTTP requests handled.",
+			Metric::IntegrationErrors => "Vendor request errors by kind.",
+			Metric::IntegrationRequestDuration => "Vendor request duration in seconds.",
+			Metric::IntegrationRequests => "Vendor requests sent.",
+			Metric::SchedulerJobDuration => "Scheduler job duration in seconds.",
+			Metric::TradingCandlesInserted => "Trading candles inserted.",
+		}
+	}
+
+	pub fn get_name(&self) -> &'static str {
+		match self {
+			Metric::AuthenticationFailures => "authentication_failures_total",
+			Metric::DatabaseOperationDuration => "mongodb_operation_duration_seconds",
+			Metric::DiscordMessagesSent => "discord_messages_sent_total",
+			Metric::HttpRequestDuration => "http_request_duration_seconds",
+			Metric::HttpRequests => "http_requests_total",
+			Metric::IntegrationErrors => "integration_errors_total",
+			Metric::IntegrationRequestDuration => "integration_request_duration_seconds",
+			Metric::IntegrationRequests => "integration_requests_total",
+			Metric::SchedulerJobDuration => "scheduler_job_duration_seconds",
+			Metric::TradingCandlesInserted => "trading_candles_inserted_total",
+		}
+	}
+
+	pub fn is_histogram(&self) -> bool {
+		matches!(
+			self,
+			Metric::DatabaseOperationDuration
+				| Metric::HttpRequestDuration
+				| Metric::IntegrationRequestDuration
+				| Metric::SchedulerJobDuration
+		)
+	}
+}
@@ -0,0 +1,69 @@
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::{get_method, Metrics};
+use actix_web::body::MessageBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::Error;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Instant;
+
+pub struct RequestMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestMetrics
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<B>;
+	type Error = Error;
+	type Transform = RequestMetricsMiddleware<S>;
+	type InitError = ();
+	type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+	fn new_transform(&self, service: S) -> Self::Future {
+		ready(Ok(RequestMetricsMiddleware {
+			service: Rc::new(service),
+		}))
+	}
+}
+
+pub struct RequestMetricsMiddleware<S> {
+	service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestMetricsMiddleware<S>
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<B>;
+	type Error = Error;
+	type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+	forward_ready!(service);
+
+	fn call(&self, request: ServiceRequest) -> Self::Future {
+		let service: Rc<S> = Rc::clone(&self.service);
+		let method: String = get_method(request.method()).to_string();
+		let started: Instant = Instant::now();
+
+		Box::pin(async move {
+			let result: Result<ServiceResponse<B>, Error> = service.call(request).await;
+
+			let (route, status): (String, String) = match &result {
+				Ok(response) => (
+					response.request().match_pattern().unwrap_or("unmatched".to_string()),
+					response.status().as_u16().to_string(),
+				),
+				Err(err) => ("unmatched".to_string(), err.as_response_error().status_code().as_u16().to_string()),
+			};
+
+			let metrics: &Metrics = Metrics::instance();
+			metrics.increment(&Metric::HttpRequests, &[("method", &method), ("route", &route), ("status", &status)], 1.0);
+			metrics.observe(&Metric::HttpRequestDuration, &[("method", &method), ("route", &route)], started.elapsed().as_secs_f64());
+
+			result
+		})
+	}
+}
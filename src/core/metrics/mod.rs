@@ -0,0 +1,4 @@
+pub mod enums;
+pub mod middleware;
+pub mod models;
+pub mod services;
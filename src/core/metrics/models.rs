@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MetricHistogram {
+	pub buckets: Vec<u64>,
+	pub count: u64,
+	pub sum: f64,
+}
@@ -0,0 +1,175 @@
+use crate::core::encryption::hmac::is_equal;
+use crate::core::config::models::AppConfig;
+use crate::core::error::enums::AppError;
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::models::MetricHistogram;
+use actix_web::http::{header, Method};
+use actix_web::{HttpRequest, HttpResponse};
+use log::error;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::{Mutex, OnceLock};
+use strum::IntoEnumIterator;
+
+const BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+pub struct Metrics {
+	pub counters: Mutex<HashMap<Metric, BTreeMap<String, f64>>>,
+	pub histograms: Mutex<HashMap<Metric, BTreeMap<String, MetricHistogram>>>,
+}
+
+impl Metrics {
+	fn new() -> Self {
+		Metrics {
+			counters: Mutex::new(HashMap::new()),
+			histograms: Mutex::new(HashMap::new()),
+		}
+	}
+
+	pub fn instance() -> &'static Self {
+		static INSTANCE: OnceLock<Metrics> = OnceLock::new();
+		INSTANCE.get_or_init(Metrics::new)
+	}
+
+	pub fn get_counter(&self, metric: &Metric, labels: &[(&str, &str)]) -> f64 {
+		match self.counters.lock() {
+			Ok(counters) => counters
+				.get(metric)
+				.and_then(|series| series.get(&get_labels(labels)))
+				.copied()
+				.unwrap_or_default(),
+			Err(_) => 0.0,
+		}
+	}
+
+	pub fn get_histogram(&self, metric: &Metric, labels: &[(&str, &str)]) -> MetricHistogram {
+		match self.histograms.lock() {
+			Ok(histograms) => histograms
+				.get(metric)
+				.and_then(|series| series.get(&get_labels(labels)))
+				.cloned()
+				.unwrap_or_default(),
+			Err(_) => MetricHistogram::default(),
+		}
+	}
+
+	pub fn increment(&self, metric: &Metric, labels: &[(&str, &str)], value: f64) {
+		match self.counters.lock() {
+			Ok(mut counters) => {
+				*counters.entry(*metric).or_default().entry(get_labels(labels)).or_default() += value;
+			}
+			Err(err) => error!("{}", err),
+		}
+	}
+
+	pub fn observe(&self, metric: &Metric, labels: &[(&str, &str)], seconds: f64) {
+		match self.histograms.lock() {
+			Ok(mut histograms) => {
+				let histogram: &mut MetricHistogram = histograms
+					.entry(*metric)
+					.or_default()
+					.entry(get_labels(labels))
+					.or_insert(MetricHistogram {
+						buckets: vec![0; BUCKETS.len()],
+						..Default::default()
+					});
+
+				for (index, bucket) in BUCKETS.iter().enumerate() {
+					if seconds <= *bucket {
+						histogram.buckets[index] += 1;
+					}
+				}
+
+				histogram.count += 1;
+				histogram.sum += seconds;
+			}
+			Err(err) => error!("{}", err),
+		}
+	}
+
+	pub fn render(&self) -> String {
+		let counters: HashMap<Metric, BTreeMap<String, f64>> = self.counters.lock().map(|counters| counters.clone()).unwrap_or_default();
+		let histograms: HashMap<Metric, BTreeMap<String, MetricHistogram>> =
+			self.histograms.lock().map(|histograms| histograms.clone()).unwrap_or_default();
+		let mut output: String = String::new();
+
+		for metric in Metric::iter() {
+			let name: &str = metric.get_name();
+			let _ = writeln!(output, "# HELP {} {}", name, metric.get_help());
+
+			if metric.is_histogram() {
+				let _ = writeln!(output, "# TYPE {} histogram", name);
+
+				for (labels, histogram) in histograms.get(&metric).into_iter().flatten() {
+					for (bucket, count) in BUCKETS.iter().zip(&histogram.buckets) {
+						let _ = writeln!(output, "{}_bucket{} {}", name, get_series(labels, Some(&bucket.to_string())), count);
+					}
+
+					let _ = writeln!(output, "{}_bucket{} {}", name, get_series(labels, Some("+Inf")), histogram.count);
+					let _ = writeln!(output, "{}_sum{} {}", name, get_series(labels, None), histogram.sum);
+					let _ = writeln!(output, "{}_count{} {}", name, get_series(labels, None), histogram.count);
+				}
+			} else {
+				let _ = writeln!(output, "# TYPE {} counter", name);
+
+				for (labels, value) in counters.get(&metric).into_iter().flatten() {
+					let _ = writeln!(output, "{}{} {}", name, get_series(labels, None), value);
+				}
+			}
+		}
+
+		output
+	}
+}
+
+pub fn get_labels(labels: &[(&str, &str)]) -> String {
+	labels
+		.iter()
+		.map(|(key, value)| format!("{}=\"{}\"", key, value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")))
+		.collect::<Vec<String>>()
+		.join(",")
+}
+
+pub fn get_method(method: &Method) -> &str {
+	match method.as_str() {
+		"CONNECT" | "DELETE" | "GET" | "HEAD" | "OPTIONS" | "PATCH" | "POST" | "PUT" | "TRACE" => method.as_str(),
+		_ => "other",
+	}
+}
+
+fn get_series(labels: &str, bucket: Option<&str>) -> String {
+	let labels: String = match bucket {
+		Some(bucket) if labels.is_empty() => format!("le=\"{}\"", bucket),
+		Some(bucket) => format!("{},le=\"{}\"", labels, bucket),
+		None => labels.to_string(),
+	};
+
+	if labels.is_empty() {
+		labels
+	} else {
+		format!("{{{}}}", labels)
+	}
+}
+
+pub async fn metrics(request: HttpRequest) -> Result<HttpResponse, AppError> {
+	let token: String = AppConfig::instance().metrics.token;
+
+	if token.is_empty() {
+		return Err(AppError::Unauthorized("Metrics token is not configured.".to_string()));
+	}
+
+	let authorization: &str = request
+		.headers()
+		.get(header::AUTHORIZATION)
+		.and_then(|value| value.to_str().ok())
+		.unwrap_or_default();
+
+	match authorization.strip_prefix("Bearer ") {
+		Some(value) if is_equal(value, &token) => {}
+		_ => return Err(AppError::Unauthorized("Invalid metrics token.".to_string())),
+	}
+
+	Ok(HttpResponse::Ok()
+		.content_type("text/plain; version=0.0.4; charset=utf-8")
+		.body(Metrics::instance().render()))
+}
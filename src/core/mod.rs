@@ -1,7 +1,14 @@
 pub mod authentication;
+pub mod authorization;
 pub mod base;
+pub mod config;
 pub mod database;
 pub mod email;
 pub mod encryption;
+pub mod error;
+pub mod health;
+pub mod history;
+pub mod metrics;
+pub mod rate_limit;
 pub mod schedulers;
 pub mod services;
@@ -0,0 +1,49 @@
+use crate::core::base::models::BaseResponse;
+use actix_web::http::header::RETRY_AFTER;
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RateLimitKey {
+	#[default]
+	Client,
+	Ip,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RateLimitError {
+	QuotaExceeded { retry_after: u64 },
+	TooManyRequests { retry_after: u64 },
+}
+
+impl RateLimitError {
+	pub fn retry_after(&self) -> u64 {
+		match self {
+			RateLimitError::QuotaExceeded { retry_after } | RateLimitError::TooManyRequests { retry_after } => *retry_after,
+		}
+	}
+}
+
+impl Display for RateLimitError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+		match self {
+			RateLimitError::QuotaExceeded { .. } => write!(formatter, "Daily quota exceeded."),
+			RateLimitError::TooManyRequests { .. } => write!(formatter, "Too many requests."),
+		}
+	}
+}
+
+impl ResponseError for RateLimitError {
+	fn status_code(&self) -> StatusCode {
+		StatusCode::TOO_MANY_REQUESTS
+	}
+
+	fn error_response(&self) -> HttpResponse {
+		HttpResponse::build(self.status_code()).insert_header((RETRY_AFTER, self.retry_after().to_string())).json(BaseResponse {
+			response: self.to_string(),
+			result: false,
+		})
+	}
+}
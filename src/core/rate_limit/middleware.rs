@@ -0,0 +1,87 @@
+use crate::core::authentication::models::AuthenticatedClient;
+use crate::core::authentication::services::get_client_ip;
+use crate::core::rate_limit::enums::RateLimitKey;
+use crate::core::rate_limit::services::check;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{web, Error, HttpMessage, ResponseError};
+use mongodb::Database;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+
+pub struct RateLimit {
+	key: RateLimitKey,
+	scope: String,
+}
+
+impl RateLimit {
+	pub fn new(scope: &str, key: RateLimitKey) -> Self {
+		RateLimit {
+			key,
+			scope: scope.to_string(),
+		}
+	}
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimit
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<EitherBody<B>>;
+	type Error = Error;
+	type Transform = RateLimitMiddleware<S>;
+	type InitError = ();
+	type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+	fn new_transform(&self, service: S) -> Self::Future {
+		ready(Ok(RateLimitMiddleware {
+			key: self.key.clone(),
+			scope: self.scope.clone(),
+			service: Rc::new(service),
+		}))
+	}
+}
+
+pub struct RateLimitMiddleware<S> {
+	key: RateLimitKey,
+	scope: String,
+	service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+	S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+	B: MessageBody + 'static,
+{
+	type Response = ServiceResponse<EitherBody<B>>;
+	type Error = Error;
+	type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+	forward_ready!(service);
+
+	fn call(&self, request: ServiceRequest) -> Self::Future {
+		let key: RateLimitKey = self.key.clone();
+		let service: Rc<S> = Rc::clone(&self.service);
+
+		let scope: String = match key {
+			RateLimitKey::Client => self.scope.clone(),
+			RateLimitKey::Ip => format!("{}_ip", self.scope),
+		};
+
+		Box::pin(async move {
+			let database: Option<web::Data<Database>> = request.app_data::<web::Data<Database>>().cloned();
+			let key: String = match key {
+				RateLimitKey::Client => request.extensions().get::<AuthenticatedClient>().map(|authenticated| authenticated.client.key.clone()).unwrap_or_default(),
+				RateLimitKey::Ip => String::new(),
+			};
+			let ip: String = get_client_ip(request.request());
+
+			match check(database.as_ref().map(|database| database.get_ref()), &scope, &key, &ip).await {
+				Ok(_) => service.call(request).await.map(ServiceResponse::map_into_left_body),
+				Err(err) => Ok(request.into_response(err.error_response()).map_into_right_body()),
+			}
+		})
+	}
+}
@@ -0,0 +1,23 @@
+use bson::serde_helpers::chrono_datetime_as_bson_datetime;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RateLimitBucket {
+	#[serde(default)]
+	pub allowed: bool,
+	#[serde(with = "chrono_datetime_as_bson_datetime")]
+	pub expired: DateTime<Utc>,
+	pub key: String,
+	pub tokens: f64,
+	#[serde(with = "chrono_datetime_as_bson_datetime")]
+	pub updated: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RateLimitQuota {
+	pub count: i64,
+	#[serde(with = "chrono_datetime_as_bson_datetime")]
+	pub expired: DateTime<Utc>,
+	pub key: String,
+}
@@ -0,0 +1,112 @@
+use crate::core::rate_limit::models::{RateLimitBucket, RateLimitQuota};
+use bson::{doc, Bson, Document};
+use chrono::{DateTime, Utc};
+use log::error;
+use mongodb::options::{IndexOptions, ReturnDocument};
+use mongodb::error::Error;
+use mongodb::{Database, IndexModel};
+use std::time::Duration;
+
+pub async fn create_index(database: &Database) {
+	for collection in ["rate_limit_bucket", "rate_limit_quota"] {
+		let index_models: Vec<IndexModel> = vec![
+			IndexModel::builder()
+				.keys(doc! {"key": 1})
+				.options(
+					IndexOptions::builder()
+						.unique(true)
+						.name(Some("key_unique_".to_string()))
+						.build(),
+				)
+				.build(),
+			IndexModel::builder()
+				.keys(doc! {"expired": 1})
+				.options(
+					IndexOptions::builder()
+						.expire_after(Some(Duration::from_secs(0)))
+						.name(Some("expired_ttl_".to_string()))
+						.build(),
+				)
+				.build(),
+		];
+
+		match database
+			.collection::<Document>(collection)
+			.create_indexes(index_models)
+			.await
+		{
+			Ok(_) => {}
+			Err(err) => {
+				error!("{:?}", err)
+			}
+		}
+	}
+}
+
+pub async fn find_one_and_update_bucket(
+	database: &Database,
+	key: &str,
+	capacity: f64,
+	refill: f64,
+	expired: DateTime<Utc>,
+) -> Result<Option<RateLimitBucket>, Error> {
+	let now: Bson = Bson::DateTime(Utc::now().into());
+
+	database
+		.collection::<RateLimitBucket>("rate_limit_bucket")
+		.find_one_and_update(
+			doc! {"key": key},
+			vec![
+				doc! {
+					"$set": {
+						"tokens": {
+							"$min": [
+								capacity,
+								{
+									"$add": [
+										{"$ifNull": ["$tokens", capacity]},
+										{
+											"$multiply": [
+												{"$divide": [{"$subtract": [now.clone(), {"$ifNull": ["$updated", now.clone()]}]}, 1000]},
+												refill,
+											]
+										},
+									]
+								},
+							]
+						},
+						"updated": now,
+					}
+				},
+				doc! {
+					"$set": {
+						"allowed": {"$gte": ["$tokens", 1]},
+						"expired": Bson::DateTime(expired.into()),
+						"tokens": {"$cond": [{"$gte": ["$tokens", 1]}, {"$subtract": ["$tokens", 1]}, "$tokens"]},
+					}
+				},
+			],
+		)
+		.upsert(true)
+		.return_document(ReturnDocument::After)
+		.await
+}
+
+pub async fn find_one_and_update_quota(
+	database: &Database,
+	key: &str,
+	expired: DateTime<Utc>,
+) -> Result<Option<RateLimitQuota>, Error> {
+	database
+		.collection::<RateLimitQuota>("rate_limit_quota")
+		.find_one_and_update(
+			doc! {"key": key},
+			doc! {
+				"$inc": {"count": 1},
+				"$setOnInsert": {"expired": Bson::DateTime(expired.into())},
+			},
+		)
+		.upsert(true)
+		.return_document(ReturnDocument::After)
+		.await
+}
@@ -0,0 +1,210 @@
+use crate::core::config::enums::Backend;
+use crate::core::config::models::{AppConfig, RateLimitScopeConfig};
+use crate::core::database::services::is_duplicate_key;
+use crate::core::rate_limit::enums::RateLimitError;
+use crate::core::rate_limit::models::{RateLimitBucket, RateLimitQuota};
+use crate::core::rate_limit::repositories::{find_one_and_update_bucket, find_one_and_update_quota};
+use chrono::{DateTime, Days, TimeDelta, Utc};
+use log::{error, warn};
+use mongodb::Database;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+const UPSERT_ATTEMPT: u32 = 3;
+
+pub struct RateLimiter {
+	pub buckets: Mutex<HashMap<String, RateLimitBucket>>,
+	pub quotas: Mutex<HashMap<String, RateLimitQuota>>,
+}
+
+impl RateLimiter {
+	fn new() -> Self {
+		RateLimiter {
+			buckets: Mutex::new(HashMap::new()),
+			quotas: Mutex::new(HashMap::new()),
+		}
+	}
+
+	pub fn instance() -> &'static Self {
+		static INSTANCE: OnceLock<RateLimiter> = OnceLock::new();
+		INSTANCE.get_or_init(RateLimiter::new)
+	}
+
+	pub fn take(&self, key: &str, capacity: f64, refill: f64) -> RateLimitBucket {
+		let now: DateTime<Utc> = Utc::now();
+
+		match self.buckets.lock() {
+			Ok(mut buckets) => {
+				if buckets.len() > 10000 {
+					buckets.retain(|_, bucket| bucket.expired > now);
+				}
+
+				let bucket: &mut RateLimitBucket = buckets.entry(key.to_string()).or_insert(RateLimitBucket {
+					key: key.to_string(),
+					tokens: capacity,
+					updated: now,
+					..Default::default()
+				});
+
+				let elapsed: f64 = now.signed_duration_since(bucket.updated).num_milliseconds() as f64 / 1000.0;
+				bucket.tokens = (bucket.tokens + elapsed * refill).min(capacity);
+				bucket.updated = now;
+				bucket.expired = get_bucket_expired(capacity, refill);
+				bucket.allowed = bucket.tokens >= 1.0;
+
+				if bucket.allowed {
+					bucket.tokens -= 1.0;
+				}
+
+				bucket.clone()
+			}
+			Err(err) => {
+				error!("{}", err);
+
+				RateLimitBucket {
+					allowed: true,
+					..Default::default()
+				}
+			}
+		}
+	}
+
+	pub fn increment(&self, key: &str) -> RateLimitQuota {
+		let now: DateTime<Utc> = Utc::now();
+
+		match self.quotas.lock() {
+			Ok(mut quotas) => {
+				quotas.retain(|_, quota| quota.expired > now);
+
+				let quota: &mut RateLimitQuota = quotas.entry(key.to_string()).or_insert(RateLimitQuota {
+					count: 0,
+					expired: get_quota_expired(),
+					key: key.to_string(),
+				});
+				quota.count += 1;
+
+				quota.clone()
+			}
+			Err(err) => {
+				error!("{}", err);
+
+				RateLimitQuota::default()
+			}
+		}
+	}
+}
+
+pub async fn check(database: Option<&Database>, scope: &str, client_key: &str, ip: &str) -> Result<(), RateLimitError> {
+	let config: RateLimitScopeConfig = get_config(scope);
+
+	if config.capacity >= 1.0 {
+		let key: String = if client_key.is_empty() {
+			format!("{}:ip:{}", scope, ip)
+		} else {
+			format!("{}:client:{}", scope, client_key)
+		};
+
+		let bucket: RateLimitBucket = take(database, &key, &config).await;
+
+		if !bucket.allowed {
+			warn!("Rate limit exceeded for {}.", key);
+
+			return Err(RateLimitError::TooManyRequests {
+				retry_after: get_retry_after(&bucket, &config),
+			});
+		}
+	}
+
+	if config.quota > 0 {
+		let key: String = if client_key.is_empty() {
+			format!("{}:ip:{}:{}", scope, ip, Utc::now().format("%Y-%m-%d"))
+		} else {
+			format!("{}:client:{}:{}", scope, client_key, Utc::now().format("%Y-%m-%d"))
+		};
+
+		let quota: RateLimitQuota = increment(database, &key).await;
+
+		if quota.count > config.quota as i64 {
+			warn!("Daily quota exceeded for {}.", key);
+
+			return Err(RateLimitError::QuotaExceeded {
+				retry_after: get_quota_expired().signed_duration_since(Utc::now()).num_seconds().max(1) as u64,
+			});
+		}
+	}
+
+	Ok(())
+}
+
+pub fn get_config(scope: &str) -> RateLimitScopeConfig {
+	AppConfig::instance().rate_limit.scopes.get(&scope.to_lowercase()).cloned().unwrap_or_default()
+}
+
+fn get_bucket_expired(capacity: f64, refill: f64) -> DateTime<Utc> {
+	let seconds: f64 = if refill > 0.0 { capacity / refill } else { 86400.0 };
+
+	Utc::now() + TimeDelta::seconds(seconds.ceil() as i64)
+}
+
+fn get_quota_expired() -> DateTime<Utc> {
+	Utc::now()
+		.date_naive()
+		.checked_add_days(Days::new(1))
+		.and_then(|date| date.and_hms_opt(0, 0, 0))
+		.map(|date| date.and_utc())
+		.unwrap_or_default()
+}
+
+fn get_retry_after(bucket: &RateLimitBucket, config: &RateLimitScopeConfig) -> u64 {
+	if config.refill <= 0.0 {
+		return 86400;
+	}
+
+	((1.0 - bucket.tokens) / config.refill).ceil().max(1.0) as u64
+}
+
+fn is_mongodb() -> bool {
+	AppConfig::instance().rate_limit.backend == Backend::Mongodb
+}
+
+async fn increment(database: Option<&Database>, key: &str) -> RateLimitQuota {
+	if let Some(database) = database.filter(|_| is_mongodb()) {
+		for _ in 0..UPSERT_ATTEMPT {
+			match find_one_and_update_quota(database, key, get_quota_expired()).await {
+				Ok(Some(quota)) => return quota,
+				Ok(None) => break,
+				Err(err) if is_duplicate_key(&err) => continue,
+				Err(err) => {
+					error!("{:?}", err);
+
+					break;
+				}
+			}
+		}
+
+		warn!("Falling back to the in-memory quota for {}.", key);
+	}
+
+	RateLimiter::instance().increment(key)
+}
+
+async fn take(database: Option<&Database>, key: &str, config: &RateLimitScopeConfig) -> RateLimitBucket {
+	if let Some(database) = database.filter(|_| is_mongodb()) {
+		for _ in 0..UPSERT_ATTEMPT {
+			match find_one_and_update_bucket(database, key, config.capacity, config.refill, get_bucket_expired(config.capacity, config.refill)).await {
+				Ok(Some(bucket)) => return bucket,
+				Ok(None) => break,
+				Err(err) if is_duplicate_key(&err) => continue,
+				Err(err) => {
+					error!("{:?}", err);
+
+					break;
+				}
+			}
+		}
+
+		warn!("Falling back to the in-memory bucket for {}.", key);
+	}
+
+	RateLimiter::instance().take(key, config.capacity, config.refill)
+}
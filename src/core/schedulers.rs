@@ -1,45 +1,46 @@
-use crate::core::base::models::BaseResponse;
-use crate::trading::asset::models::Asset;
-use crate::trading::asset::repositories::find_by_sync_synchronized;
-use crate::trading::data::enums::Timeframe;
-use crate::trading::data::services::get_realtime;
-use log::info;
-use mongodb::Database;
-use std::sync::Arc;
+use crate::core::authentication::services::NonceStore;
+use crate::core::health::services::HealthMonitor;
+use chrono::Utc;
+use log::error;
 use tokio::task;
 use tokio_cron_scheduler::{Job, JobScheduler};
 
-pub fn scheduler_eodhd_intraday_1m(database: &Database) {
-	let database_clone: Database = database.clone();
-
+pub fn scheduler_nonce_purge() {
 	task::spawn(async move {
-		let scheduler: JobScheduler = JobScheduler::new().await.unwrap();
-		let database_clone: Arc<Database> = Arc::new(database_clone);
-
-		scheduler.add(
-			Job::new_async("0 */1 * * * *", move |_uuid, _l| {
-				let database_arc_clone: Arc<Database> = Arc::clone(&database_clone);
-
-				Box::pin(async move {
-					let assets: Option<Vec<Asset>> = find_by_sync_synchronized(&database_arc_clone, &true).await;
-
-					if !assets.is_none() && !assets.is_some() {
-						for asset in assets.unwrap_or_default() {
-							let response: BaseResponse = get_realtime(
-								&database_arc_clone,
-								&asset,
-								&Timeframe::OneMinute,
-							).await;
-
-							info!("{}", response.response);
-						}
-
-						info!("Trading data get_realtime executed.");
-					}
-				})
-			}).unwrap(),
-		).await.unwrap();
-
-		scheduler.start().await.unwrap();
+		let name: &str = "authentication_nonce_purge";
+		let schedule: &str = "30 */1 * * * *";
+		let scheduler: JobScheduler = match JobScheduler::new().await {
+			Ok(scheduler) => scheduler,
+			Err(err) => {
+				error!("Scheduler {} failed to create: {}", name, err);
+
+				return;
+			}
+		};
+		let job: Job = match Job::new_async(schedule, move |_uuid, _l| {
+			Box::pin(async move {
+				NonceStore::instance().purge();
+
+				HealthMonitor::instance().finish_job(name, Utc::now());
+			})
+		}) {
+			Ok(job) => job,
+			Err(err) => {
+				error!("Scheduler {} failed to create job: {}", name, err);
+
+				return;
+			}
+		};
+
+		if let Err(err) = scheduler.add(job).await {
+			error!("Scheduler {} failed to add job: {}", name, err);
+
+			return;
+		}
+
+		match scheduler.start().await {
+			Ok(_) => HealthMonitor::instance().start_job(name, schedule),
+			Err(err) => error!("Scheduler {} failed to start: {}", name, err),
+		}
 	});
 }
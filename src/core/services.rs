@@ -1,19 +1,29 @@
+use crate::client::repositories::create_index as create_client_index;
+use crate::core::authentication::repositories::create_index as create_nonce_index;
 use crate::core::base::enums::Status;
+use crate::core::config::models::AppConfig;
+use crate::core::health::services::HealthMonitor;
+use crate::core::rate_limit::repositories::create_index as create_rate_limit_index;
 use crate::integration::discord;
+use crate::integration::enums::Vendor;
+use crate::integration::services::get_target;
 use crate::trading::asset::enums::Provider;
 use crate::trading::asset::models::{Asset, AssetSync};
 use crate::trading::asset::repositories::{create_index, find_by_sync_synchronized, insert_one};
 use crate::trading::data::enums::Timeframe;
 use crate::trading::data::repositories::create_index as create_data_index;
 use crate::trading::data::services::sync;
+use crate::user::log::repositories::create_index as create_user_log_index;
+use crate::user::repositories::create_index as create_user_index;
+use crate::user::security::repositories::create_index as create_user_security_index;
 use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
 use dotenvy::from_filename;
 use flexi_logger::filter::{LogLineFilter, LogLineWriter};
-use flexi_logger::{detailed_format, DeferredNow, FileSpec, Logger, WriteMode};
-use log::{error, Record};
+use flexi_logger::{detailed_format, DeferredNow, FileSpec, FlexiLoggerError, Logger, WriteMode};
+use log::{error, Level, Record};
 use mongodb::Database;
 use serenity::all::GatewayIntents;
-use std::env;
+use strum::IntoEnumIterator;
 use tokio::task;
 
 struct LogFilter;
@@ -31,15 +41,19 @@ impl LogLineFilter for LogFilter {
 			.iter()
 			.any(|module| record.module_path().unwrap_or_default().starts_with(module))
 		{
-			Ok(())
-		} else {
-			writer.write(now, record)
+			return Ok(());
 		}
+
+		if record.level() == Level::Error {
+			HealthMonitor::instance().record_error(record.target(), &record.args().to_string());
+		}
+
+		writer.write(now, record)
 	}
 }
 
 pub fn initialize_discord() {
-	let discord_token: String = env::var("API_DISCORD_TOKEN").unwrap_or_default();
+	let discord_token: String = AppConfig::instance().discord.token;
 	let intents: GatewayIntents = GatewayIntents::GUILD_MESSAGES | GatewayIntents::MESSAGE_CONTENT;
 
 	task::spawn(async move {
@@ -55,6 +69,8 @@ pub fn initialize_discord() {
 			}
 			Err(err) => error!("{:?}", err),
 		};
+
+		discord::services::Discord::instance().set_connected(false);
 	});
 }
 
@@ -66,9 +82,15 @@ pub fn initialize_env() {
 	});
 }
 
-pub fn initialize_log() {
-	Logger::try_with_str("info")
-		.unwrap()
+pub fn initialize_log(config: &AppConfig) -> Result<(), FlexiLoggerError> {
+	let mut specification: String = "info".to_string();
+
+	for vendor in Vendor::iter() {
+		let level: &str = config.log.integration.level.get(&vendor).map(String::as_str).unwrap_or("info");
+		specification += &format!(", {}={}", get_target(&vendor), level);
+	}
+
+	Logger::try_with_str(&specification)?
 		.filter(Box::new(LogFilter))
 		.write_mode(WriteMode::BufferAndFlush)
 		.format(detailed_format)
@@ -82,12 +104,19 @@ pub fn initialize_log() {
 			flexi_logger::Naming::Numbers,
 			flexi_logger::Cleanup::KeepLogFiles(7),
 		)
-		.start()
-		.unwrap();
+		.start()?;
+
+	Ok(())
 }
 
 pub async fn initialize_task(database: &Database) {
 	create_index(database).await;
+	create_client_index(database).await;
+	create_nonce_index(database).await;
+	create_rate_limit_index(database).await;
+	create_user_index(database).await;
+	create_user_log_index(database).await;
+	create_user_security_index(database).await;
 
 	let last: DateTime<Utc> = Utc::now() - Duration::days(3650);
 	let _ = insert_one(
@@ -96,6 +125,7 @@ pub async fn initialize_task(database: &Database) {
 			provider: Provider::EODHD,
 			status: Status::Active,
 			sync: AssetSync {
+				error: None,
 				last: Utc.with_ymd_and_hms(last.year(), 1, 1, 0, 0, 0).unwrap(),
 				synchronized: false,
 				symbol: "C:XAUUSD".to_string(),
@@ -109,7 +139,7 @@ pub async fn initialize_task(database: &Database) {
 
 	let assets: Option<Vec<Asset>> = find_by_sync_synchronized(database, &false).await;
 
-	if !assets.is_none() {
+	if assets.is_some() {
 		for asset in assets.unwrap_or_default() {
 			create_data_index(database, &asset.ticker).await;
 
@@ -1,15 +1,15 @@
+use crate::core::authentication::services::generate_nonce;
 use crate::core::base::models::BaseResponse;
-use crate::core::encryption::rsa::encrypt;
+use crate::core::config::models::AppConfig;
+use crate::core::encryption::rsa::{encrypt, read_client_key};
 use crate::integration::ai::models::{PredictRequest, PredictResponse, TrainRequest};
+use crate::integration::enums::{IntegrationError, Vendor};
 use crate::integration::services::post_request;
 use chrono::Utc;
-use log::info;
 use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, to_value, Value};
 use std::collections::HashMap;
-use std::env;
 
 fn initialize_body<T>(body: &T, path: &str) -> T
 where
@@ -21,8 +21,8 @@ where
     map.insert(
         "token".to_string(),
         to_value(encrypt(
-            &format!("{}~{}", path, Utc::now().to_rfc3339()),
-            &env::var("API_AI_KEY").unwrap_or_default(),
+            &format!("{}~{}~{}", path, Utc::now().to_rfc3339(), generate_nonce()),
+            &read_client_key(&AppConfig::instance().ai.key, "public-key.pem"),
         ))
         .unwrap_or_default(),
     );
@@ -38,7 +38,7 @@ fn initialize_header() -> HeaderMap {
     );
     headers.insert(
         "pld-key",
-        HeaderValue::from_str(&env::var("API_AI_KEY").unwrap_or_default())
+        HeaderValue::from_str(&AppConfig::instance().ai.key)
             .ok()
             .unwrap(),
     );
@@ -46,38 +46,34 @@ fn initialize_header() -> HeaderMap {
     headers
 }
 
-pub async fn predict(params: &PredictRequest) -> Option<PredictResponse> {
-    let client: Client = Client::new();
+pub async fn predict(params: &PredictRequest) -> Result<PredictResponse, IntegrationError> {
     let url: String = format!(
         "{}/trading/data/predict",
-        env::var("API_AI_BASE_URL").unwrap_or_default()
+        AppConfig::instance().ai.base_url
     );
 
-    info!("Request to {}\nHeaders : \nBody : ", url);
-
     post_request(
-        &client,
+        &Vendor::Ai,
         &url,
         &initialize_header(),
-        &initialize_body(params, &"/trading/data/predict"),
+        || initialize_body(params, "/trading/data/predict"),
+        true,
     )
     .await
 }
 
-pub async fn train(params: &TrainRequest) -> Option<BaseResponse> {
-    let client: Client = Client::new();
+pub async fn train(params: &TrainRequest) -> Result<BaseResponse, IntegrationError> {
     let url: String = format!(
         "{}/trading/data/train",
-        env::var("API_AI_BASE_URL").unwrap_or_default()
+        AppConfig::instance().ai.base_url
     );
 
-    info!("Request to {}\nHeaders : \nBody : ", url);
-
     post_request(
-        &client,
+        &Vendor::Ai,
         &url,
         &initialize_header(),
-        &initialize_body(params, &"/trading/data/train"),
+        || initialize_body(params, "/trading/data/train"),
+        false,
     )
     .await
 }
@@ -1,14 +1,17 @@
+use crate::core::config::models::AppConfig;
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
 use chrono::{DateTime, Utc};
 use log::{error, info};
 use serenity::all::MessageId;
 use serenity::async_trait;
 use serenity::builder::GetMessages;
+use serenity::gateway::{ConnectionStage, ShardStageUpdateEvent};
 use serenity::model::channel::Message;
 use serenity::model::gateway::Ready;
 use serenity::model::id::ChannelId;
 use serenity::model::Timestamp;
 use serenity::prelude::{Context, EventHandler};
-use std::env;
 use std::sync::{OnceLock, RwLock};
 use std::time::Duration;
 use tokio::task;
@@ -16,6 +19,7 @@ use tokio::time::{interval, sleep, Interval};
 
 pub struct Discord {
 	pub channel_id: RwLock<Option<u64>>,
+	pub connected: RwLock<bool>,
 	pub message: RwLock<Option<String>>,
 }
 
@@ -23,6 +27,7 @@ impl Discord {
 	fn new() -> Self {
 		Discord {
 			channel_id: RwLock::new(None),
+			connected: RwLock::new(false),
 			message: RwLock::new(None),
 		}
 	}
@@ -33,7 +38,11 @@ impl Discord {
 	}
 
 	pub fn get_channel_id(&self) -> Option<u64> {
-		self.channel_id.write().ok()?.clone()
+		*self.channel_id.write().ok()?
+	}
+
+	pub fn is_connected(&self) -> bool {
+		self.connected.read().map(|connected| *connected).unwrap_or_default()
 	}
 
 	pub fn get_message(&self) -> Option<String> {
@@ -49,6 +58,15 @@ impl Discord {
 		}
 	}
 
+	pub fn set_connected(&self, connected: bool) {
+		match self.connected.write() {
+			Ok(mut _bool) => {
+				*_bool = connected;
+			}
+			Err(err) => error!("{}", err),
+		}
+	}
+
 	pub fn set_message(&self, message: &str) {
 		match self.message.write() {
 			Ok(mut string) => {
@@ -72,12 +90,17 @@ impl EventHandler for Handler {
 		}
 	}
 
+	async fn shard_stage_update(&self, _context: Context, event: ShardStageUpdateEvent) {
+		Discord::instance().set_connected(event.new == ConnectionStage::Connected);
+	}
+
 	async fn ready(&self, context: Context, ready: Ready) {
 		info!("{} discord bot is ready.", ready.user.name.clone());
 
 		let discord: &Discord = Discord::instance();
+		discord.set_connected(true);
 
-		let channel_id: u64 = env::var("API_DISCORD_CHANNEL_ID").unwrap_or_default().parse::<u64>().unwrap_or_default();
+		let channel_id: u64 = AppConfig::instance().discord.channel_id;
 		discord.set_channel_id(&channel_id);
 
 		let context_send: Context = context.clone();
@@ -90,7 +113,7 @@ impl EventHandler for Handler {
 					let discord_channel_id: ChannelId = ChannelId::from(discord.get_channel_id().unwrap_or_default());
 
 					match discord_channel_id.say(&context_send.http, message).await {
-						Ok(_) => {}
+						Ok(_) => Metrics::instance().increment(&Metric::DiscordMessagesSent, &[("channel", &discord_channel_id.to_string())], 1.0),
 						Err(err) => error!("{:?}", err),
 					}
 				}
@@ -140,7 +163,19 @@ async fn delete_message(context: &Context, channel_id: &ChannelId) {
 
 pub async fn send_visitor(message: &str) {
 	let discord: &Discord = Discord::instance();
-	let channel_id: u64 = env::var("API_DISCORD_CHANNEL_ID_VISITOR_MESSAGE").unwrap_or_default().parse::<u64>().unwrap_or_default();
+	let channel_id: u64 = AppConfig::instance().discord.channel_id_visitor_message;
+	discord.set_channel_id(&channel_id);
+	discord.set_message(message);
+}
+
+pub async fn send_security(message: &str) {
+	let channel_id: u64 = AppConfig::instance().discord.channel_id_security;
+
+	if channel_id == 0 {
+		return;
+	}
+
+	let discord: &Discord = Discord::instance();
 	discord.set_channel_id(&channel_id);
 	discord.set_message(message);
 }
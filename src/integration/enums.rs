@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Serialize)]
+pub enum CircuitState {
+	#[default]
+	Closed,
+	HalfOpen,
+	Open,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum IntegrationError {
+	Cassette(String),
+	CircuitOpen { retry_after: u64, vendor: Vendor },
+	Network(String),
+	Parse { body: String, error: String },
+	RateLimit { body: String, retry_after: Option<u64> },
+	Status { body: String, status: u16 },
+}
+
+impl IntegrationError {
+	pub fn get_kind(&self) -> &'static str {
+		match self {
+			IntegrationError::Cassette(_) => "cassette",
+			IntegrationError::CircuitOpen { .. } => "circuit_open",
+			IntegrationError::Network(_) => "network",
+			IntegrationError::Parse { .. } => "parse",
+			IntegrationError::RateLimit { .. } => "rate_limit",
+			IntegrationError::Status { .. } => "status",
+		}
+	}
+
+	pub fn is_misconfigured(&self) -> bool {
+		matches!(self, IntegrationError::Status { status: 400 | 401 | 403, .. })
+	}
+
+	pub fn is_not_found(&self) -> bool {
+		matches!(self, IntegrationError::Status { status: 404, .. })
+	}
+
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			IntegrationError::CircuitOpen { .. } | IntegrationError::Network(_) | IntegrationError::RateLimit { .. } => true,
+			IntegrationError::Status { status, .. } => *status >= 500,
+			IntegrationError::Cassette(_) | IntegrationError::Parse { .. } => false,
+		}
+	}
+}
+
+impl Display for IntegrationError {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+		match self {
+			IntegrationError::Cassette(error) => write!(formatter, "Cassette error: {}", error),
+			IntegrationError::CircuitOpen { retry_after, vendor } => write!(formatter, "Circuit open for {:?}, retry after {} seconds", vendor, retry_after),
+			IntegrationError::Network(error) => write!(formatter, "Network error: {}", error),
+			IntegrationError::Parse { error, .. } => write!(formatter, "Invalid response: {}", error),
+			IntegrationError::RateLimit { retry_after, .. } => match retry_after {
+				Some(seconds) => write!(formatter, "Rate limited, retry after {} seconds", seconds),
+				None => write!(formatter, "Rate limited"),
+			},
+			IntegrationError::Status { body, status } => write!(formatter, "Status {}: {}", status, body),
+		}
+	}
+}
+
+impl Error for IntegrationError {}
+
+#[derive(Clone, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Serialize)]
+pub enum IntegrationMode {
+	#[default]
+	Live,
+	Record,
+	Replay,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, EnumIter, Eq, Hash, PartialEq, Serialize)]
+pub enum Vendor {
+	Ai,
+	#[default]
+	Eodhd,
+	Massive,
+}
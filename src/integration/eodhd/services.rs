@@ -1,47 +1,40 @@
+use crate::core::config::models::{AppConfig, VendorConfig};
+use crate::integration::enums::{IntegrationError, Vendor};
 use crate::integration::eodhd::models::{ExchangeSymbolRequest, ExchangeSymbolResponse, IntradayRequest, IntradayResponse, RealtimeRequest, RealtimeResponse};
 use crate::integration::services::{get_request, initialize_params};
-use log::info;
-use reqwest::Client;
-use std::env;
 
-pub async fn exchange_symbol(params: &ExchangeSymbolRequest) -> Option<Vec<ExchangeSymbolResponse>> {
-	let client: Client = Client::new();
+pub async fn exchange_symbol(params: &ExchangeSymbolRequest) -> Result<Vec<ExchangeSymbolResponse>, IntegrationError> {
+	let config: VendorConfig = AppConfig::instance().eodhd;
 	let url: String = format!(
 		"{}/exchange-symbol-list/{}?api_token={}&fmt=json",
-		env::var("API_EODHD_BASE_URL").unwrap_or_default(),
+		config.base_url,
 		params.code,
-		env::var("API_EODHD_TOKEN").unwrap_or_default()
+		config.key
 	) + &initialize_params(params);
 
-	info!("Request to {}\nHeaders : \nBody : ", url);
-
-	get_request(&client, &url).await
+	get_request(&Vendor::Eodhd, &url).await
 }
 
-pub async fn intraday(params: &IntradayRequest) -> Option<Vec<IntradayResponse>> {
-	let client: Client = Client::new();
+pub async fn intraday(params: &IntradayRequest) -> Result<Vec<IntradayResponse>, IntegrationError> {
+	let config: VendorConfig = AppConfig::instance().eodhd;
 	let url: String = format!(
 		"{}/intraday/{}?api_token={}&fmt=json",
-		env::var("API_EODHD_BASE_URL").unwrap_or_default(),
+		config.base_url,
 		params.symbol,
-		env::var("API_EODHD_TOKEN").unwrap_or_default()
+		config.key
 	) + &initialize_params(params);
 
-	info!("Request to {}\nHeaders : \nBody : ", url);
-
-	get_request(&client, &url).await
+	get_request(&Vendor::Eodhd, &url).await
 }
 
-pub async fn realtime(params: &RealtimeRequest) -> Option<Vec<RealtimeResponse>> {
-	let client: Client = Client::new();
+pub async fn realtime(params: &RealtimeRequest) -> Result<Vec<RealtimeResponse>, IntegrationError> {
+	let config: VendorConfig = AppConfig::instance().eodhd;
 	let url: String = format!(
 		"{}/real-time/{}?api_token={}&fmt=json",
-		env::var("API_EODHD_BASE_URL").unwrap_or_default(),
+		config.base_url,
 		params.symbol,
-		env::var("API_EODHD_TOKEN").unwrap_or_default()
+		config.key
 	) + &initialize_params(params);
 
-	info!("Request to {}\nHeaders : \nBody : ", url);
-
-	get_request(&client, &url).await
+	get_request(&Vendor::Eodhd, &url).await
 }
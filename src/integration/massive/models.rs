@@ -10,10 +10,10 @@ fn to_datetime<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
 	let secs: i64 = timestamp / 1000;
 	let nsecs: i64 = (timestamp % 1000) * 1_000_000;
 
-	Ok(Utc
+	Utc
 		.timestamp_opt(secs, nsecs as u32)
 		.single()
-		.ok_or_else(|| serde::de::Error::custom(format!("Invalid timestamp: {}", timestamp)))?)
+		.ok_or_else(|| serde::de::Error::custom(format!("Invalid timestamp: {}", timestamp)))
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -1,19 +1,17 @@
+use crate::core::config::models::AppConfig;
+use crate::integration::enums::{IntegrationError, Vendor};
 use crate::integration::massive::models::{AggregateTickerRequest, AggregateTickerResponse};
 use crate::integration::services::{
 	get_request, initialize_params as initialize_params_integration,
 };
-use log::info;
-use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::env;
 
 pub async fn aggregate_ticker(
 	params: &AggregateTickerRequest,
-) -> Option<AggregateTickerResponse> {
-	let client: Client = Client::new();
+) -> Result<AggregateTickerResponse, IntegrationError> {
 	let url: String = format!(
 		"{}/v2/aggs/ticker/{}/range/{}/{}/{}/{}?{}",
-		env::var("API_MASSIVE_BASE_URL").unwrap_or_default(),
+		AppConfig::instance().massive.base_url,
 		params.ticker,
 		params.multiplier,
 		serde_json::to_string(&params.timespan)
@@ -24,9 +22,7 @@ pub async fn aggregate_ticker(
 		&initialize_params(params)
 	);
 
-	info!("Request to {}\nHeaders : \nBody : ", url);
-
-	get_request(&client, &url).await
+	get_request(&Vendor::Massive, &url).await
 }
 
 fn initialize_params<T>(params: &T) -> String
@@ -37,7 +33,7 @@ fn initialize_params<T>(params: &T) -> String
 		"&apiKey=",
 		&format!(
 			"&apiKey={}",
-			env::var("API_MASSIVE_KEY").unwrap_or_default()
+			AppConfig::instance().massive.key
 		),
 	);
 
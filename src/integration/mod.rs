@@ -1,5 +1,8 @@
 pub mod ai;
 pub mod discord;
+pub mod enums;
 pub mod eodhd;
 pub mod massive;
+pub mod models;
+pub mod routes;
 pub mod services;
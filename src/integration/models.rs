@@ -0,0 +1,39 @@
+use crate::integration::enums::{CircuitState, Vendor};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Cassette {
+	pub request: String,
+	pub response: CassetteResponse,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CassetteResponse {
+	pub body: String,
+	pub retry_after: Option<u64>,
+	pub status: u16,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Circuit {
+	pub error: Option<String>,
+	pub failures: u32,
+	pub failure: Option<DateTime<Utc>>,
+	pub opened: Option<DateTime<Utc>>,
+	pub state: CircuitState,
+	pub success: Option<DateTime<Utc>>,
+	pub vendor: Vendor,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IntegrationStatusRequest {
+	pub token: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IntegrationStatusResponse {
+	pub circuits: Vec<Circuit>,
+	pub response: String,
+	pub result: bool,
+}
@@ -0,0 +1,10 @@
+use crate::integration::services::status;
+use actix_web::{web, HttpResponse};
+
+pub fn config(config: &mut web::ServiceConfig) {
+	config.service(
+		web::resource("/status").route(web::post().to(status)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/status/").route(web::post().to(status)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	);
+}
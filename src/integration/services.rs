@@ -1,8 +1,407 @@
-use log::{error, info};
-use reqwest::header::HeaderMap;
-use reqwest::{Client, Response};
+use crate::core::authentication::models::AuthenticatedClient;
+use crate::core::config::models::{AppConfig, LogIntegrationConfig};
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
+use crate::integration::enums::{CircuitState, IntegrationError, IntegrationMode, Vendor};
+use crate::integration::models::{Cassette, CassetteResponse, Circuit, IntegrationStatusRequest, IntegrationStatusResponse};
+use actix_web::{web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use log::{debug, error, info, log, warn, Level};
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, Request, RequestBuilder, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::PathBuf;
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+use strum::IntoEnumIterator;
+use tokio::time::sleep;
+
+pub struct CircuitBreaker {
+    pub circuits: RwLock<HashMap<Vendor, Circuit>>,
+}
+
+impl CircuitBreaker {
+    fn new() -> Self {
+        CircuitBreaker {
+            circuits: RwLock::new(
+                Vendor::iter()
+                    .map(|vendor| {
+                        (
+                            vendor,
+                            Circuit {
+                                vendor,
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn instance() -> &'static Self {
+        static INSTANCE: OnceLock<CircuitBreaker> = OnceLock::new();
+        INSTANCE.get_or_init(CircuitBreaker::new)
+    }
+
+    pub fn get_circuit(&self, vendor: &Vendor) -> Circuit {
+        match self.circuits.read() {
+            Ok(circuits) => circuits.get(vendor).cloned().unwrap_or_default(),
+            Err(err) => {
+                error!("{}", err);
+                Circuit::default()
+            }
+        }
+    }
+
+    pub fn get_circuits(&self) -> Vec<Circuit> {
+        Vendor::iter().map(|vendor| self.get_circuit(&vendor)).collect()
+    }
+
+    pub fn is_open(&self, vendor: &Vendor) -> bool {
+        self.get_circuit(vendor).state == CircuitState::Open && self.get_retry_after(vendor) > 0
+    }
+
+    pub fn allow(&self, vendor: &Vendor) -> Result<(), IntegrationError> {
+        let retry_after: u64 = self.get_retry_after(vendor);
+
+        match self.circuits.write() {
+            Ok(mut circuits) => {
+                if let Some(circuit) = circuits.get_mut(vendor) {
+                    match circuit.state {
+                        CircuitState::Closed => {}
+                        CircuitState::HalfOpen => {
+                            return Err(IntegrationError::CircuitOpen {
+                                retry_after: retry_after.max(1),
+                                vendor: *vendor,
+                            });
+                        }
+                        CircuitState::Open => {
+                            if retry_after > 0 {
+                                return Err(IntegrationError::CircuitOpen {
+                                    retry_after,
+                                    vendor: *vendor,
+                                });
+                            }
+
+                            info!("Circuit for {:?} is half open.", vendor);
+                            circuit.state = CircuitState::HalfOpen;
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+            Err(err) => {
+                error!("{}", err);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn record_failure(&self, vendor: &Vendor, err: &IntegrationError) {
+        let threshold: u32 = AppConfig::instance().integration.circuit_threshold;
+
+        match self.circuits.write() {
+            Ok(mut circuits) => {
+                if let Some(circuit) = circuits.get_mut(vendor) {
+                    circuit.error = Some(err.to_string());
+                    circuit.failure = Some(Utc::now());
+                    circuit.failures += 1;
+
+                    if circuit.state == CircuitState::HalfOpen || circuit.failures >= threshold {
+                        warn!("Circuit for {:?} is open after {} failures.", vendor, circuit.failures);
+                        circuit.opened = Some(Utc::now());
+                        circuit.state = CircuitState::Open;
+                    }
+                }
+            }
+            Err(err) => error!("{}", err),
+        }
+    }
+
+    pub fn record_success(&self, vendor: &Vendor) {
+        match self.circuits.write() {
+            Ok(mut circuits) => {
+                if let Some(circuit) = circuits.get_mut(vendor) {
+                    if circuit.state != CircuitState::Closed {
+                        info!("Circuit for {:?} is closed.", vendor);
+                    }
+
+                    circuit.failures = 0;
+                    circuit.opened = None;
+                    circuit.state = CircuitState::Closed;
+                    circuit.success = Some(Utc::now());
+                }
+            }
+            Err(err) => error!("{}", err),
+        }
+    }
+
+    pub fn record_response(&self, vendor: &Vendor) {
+        if self.get_circuit(vendor).state == CircuitState::HalfOpen {
+            self.record_success(vendor);
+        }
+    }
+
+    pub fn release(&self, vendor: &Vendor) {
+        match self.circuits.write() {
+            Ok(mut circuits) => {
+                if let Some(circuit) = circuits.get_mut(vendor)
+                    && circuit.state == CircuitState::HalfOpen
+                {
+                    circuit.state = CircuitState::Open;
+                }
+            }
+            Err(err) => error!("{}", err),
+        }
+    }
+
+    pub fn reset(&self, vendor: &Vendor) {
+        match self.circuits.write() {
+            Ok(mut circuits) => {
+                circuits.insert(
+                    *vendor,
+                    Circuit {
+                        vendor: *vendor,
+                        ..Default::default()
+                    },
+                );
+            }
+            Err(err) => error!("{}", err),
+        }
+    }
+
+    fn get_retry_after(&self, vendor: &Vendor) -> u64 {
+        let cooldown: i64 = AppConfig::instance().integration.circuit_cooldown;
+        let opened: DateTime<Utc> = self.get_circuit(vendor).opened.unwrap_or_default();
+
+        (cooldown - Utc::now().signed_duration_since(opened).num_seconds()).max(0) as u64
+    }
+}
+
+fn get_client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .pool_idle_timeout(Duration::from_secs(90))
+            .pool_max_idle_per_host(8)
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+fn get_backoff(attempt: u32, err: &IntegrationError) -> Duration {
+    let backoff: u64 = AppConfig::instance().integration.backoff;
+    let jitter: u64 = rand::thread_rng().gen_range(0..=backoff);
+    let delay: Duration = Duration::from_millis(backoff.saturating_mul(2u64.saturating_pow(attempt)) + jitter);
+
+    match get_retry_after_delay(err) {
+        Some(retry_after) => delay.max(retry_after),
+        None => delay,
+    }
+}
+
+fn get_retry_after_delay(err: &IntegrationError) -> Option<Duration> {
+    match err {
+        IntegrationError::RateLimit {
+            retry_after: Some(retry_after),
+            ..
+        } => Some(Duration::from_secs(*retry_after)),
+        _ => None,
+    }
+}
+
+fn get_timeout(vendor: &Vendor) -> Duration {
+    let config: AppConfig = AppConfig::instance();
+
+    Duration::from_secs(match vendor {
+        Vendor::Ai => config.ai.timeout,
+        Vendor::Eodhd => config.eodhd.timeout,
+        Vendor::Massive => config.massive.timeout,
+    })
+}
+
+pub fn get_retry_after(value: &str) -> Option<u64> {
+    match value.trim().parse::<u64>() {
+        Ok(retry_after) => Some(retry_after),
+        Err(_) => DateTime::parse_from_rfc2822(value.trim())
+            .ok()
+            .map(|date| date.to_utc().signed_duration_since(Utc::now()).num_seconds().max(0) as u64),
+    }
+}
+
+pub fn get_cassette_path(vendor: &Vendor, request: &str) -> PathBuf {
+    let directory: String = AppConfig::instance().integration.cassette_directory;
+
+    PathBuf::from(directory)
+        .join(get_name(vendor))
+        .join(format!("{}.json", hex::encode(Sha256::digest(request.as_bytes()))))
+}
+
+fn get_name(vendor: &Vendor) -> &'static str {
+    match vendor {
+        Vendor::Ai => "ai",
+        Vendor::Eodhd => "eodhd",
+        Vendor::Massive => "massive",
+    }
+}
+
+pub fn get_target(vendor: &Vendor) -> &'static str {
+    match vendor {
+        Vendor::Ai => "integration::ai",
+        Vendor::Eodhd => "integration::eodhd",
+        Vendor::Massive => "integration::massive",
+    }
+}
+
+pub fn normalize_request(method: &str, url: &str, body: Option<&[u8]>) -> String {
+    let secrets: Vec<String> = AppConfig::instance().log.integration.secret_params;
+
+    let path: &str = match url.split_once("://") {
+        Some((_, address)) => address.find('/').map(|index| &address[index..]).unwrap_or("/"),
+        None => url,
+    };
+
+    let mut request: String = match path.split_once('?') {
+        Some((path, query)) => {
+            let mut params: Vec<&str> = query
+                .split('&')
+                .filter(|param| {
+                    let key: &str = param.split_once('=').map(|(key, _)| key).unwrap_or(param);
+
+                    !param.is_empty() && !secrets.contains(&key.to_lowercase())
+                })
+                .collect();
+            params.sort();
+
+            format!("{} {}?{}", method, path, params.join("&"))
+        }
+        None => format!("{} {}", method, path),
+    };
+
+    if let Some(body) = body {
+        let body: String = match serde_json::from_slice::<Value>(body) {
+            Ok(mut json) => {
+                remove_value(&mut json, &secrets);
+                json.to_string()
+            }
+            Err(_) => String::from_utf8_lossy(body).to_string(),
+        };
+
+        request += &format!("\n{}", body);
+    }
+
+    request
+}
+
+pub fn redact_body(body: &str) -> String {
+    let config: LogIntegrationConfig = AppConfig::instance().log.integration;
+    let secrets: Vec<String> = config.secret_params;
+    let limit: usize = config.body_limit;
+
+    let body: String = match serde_json::from_str::<Value>(body) {
+        Ok(mut json) => {
+            redact_value(&mut json, &secrets);
+            json.to_string()
+        }
+        Err(_) => body.to_string(),
+    };
+
+    if body.len() > limit {
+        let mut end: usize = limit;
+
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        format!("{}... ({} bytes truncated)", &body[..end], body.len() - end)
+    } else {
+        body
+    }
+}
+
+pub fn redact_headers(headers: &HeaderMap) -> String {
+    let secrets: Vec<String> = AppConfig::instance().log.integration.secret_headers;
+
+    let headers: Vec<String> = headers
+        .iter()
+        .map(|(name, value)| {
+            if secrets.contains(&name.as_str().to_lowercase()) {
+                format!("{:?}: \"[REDACTED]\"", name.as_str())
+            } else {
+                format!("{:?}: {:?}", name.as_str(), value)
+            }
+        })
+        .collect();
+
+    format!("{{{}}}", headers.join(", "))
+}
+
+pub fn redact_url(url: &str) -> String {
+    let secrets: Vec<String> = AppConfig::instance().log.integration.secret_params;
+
+    match url.split_once('?') {
+        Some((path, query)) => {
+            let params: Vec<String> = query
+                .split('&')
+                .map(|param| match param.split_once('=') {
+                    Some((key, _)) if secrets.contains(&key.to_lowercase()) => {
+                        format!("{}=[REDACTED]", key)
+                    }
+                    _ => param.to_string(),
+                })
+                .collect();
+
+            format!("{}?{}", path, params.join("&"))
+        }
+        None => url.to_string(),
+    }
+}
+
+fn remove_value(value: &mut Value, secrets: &[String]) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                remove_value(value, secrets);
+            }
+        }
+        Value::Object(map) => {
+            map.retain(|key, _| !secrets.contains(&key.to_lowercase()));
+
+            for value in map.values_mut() {
+                remove_value(value, secrets);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn redact_value(value: &mut Value, secrets: &[String]) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                redact_value(value, secrets);
+            }
+        }
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if secrets.contains(&key.to_lowercase()) {
+                    *value = Value::String("[REDACTED]".to_string());
+                } else {
+                    redact_value(value, secrets);
+                }
+            }
+        }
+        _ => {}
+    }
+}
 
 pub fn initialize_params<T>(params: &T) -> String
 where
@@ -10,7 +409,7 @@ where
 {
     let mut url: String = "".to_string();
 
-    let json: Value = serde_json::to_value(&params).unwrap();
+    let json: Value = serde_json::to_value(params).unwrap();
 
     if let Value::Object(map) = json {
         for (key, value) in map {
@@ -27,61 +426,273 @@ where
     url
 }
 
-async fn initialize_response<T>(response: Response, url: &str) -> Option<T> where
+async fn initialize_cassette(
+    response: Response,
+    vendor: &Vendor,
+    request: String,
+) -> Result<Cassette, IntegrationError> {
+    let status: StatusCode = response.status();
+    let retry_after: Option<u64> = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(get_retry_after);
+    info!(
+        target: get_target(vendor),
+        "Response from {}\nStatus: {}\nHeaders : {}",
+        redact_url(response.url().as_str()),
+        status,
+        redact_headers(response.headers())
+    );
+    let body: String = response
+        .text()
+        .await
+        .map_err(|err| IntegrationError::Network(err.without_url().to_string()))?;
+
+    Ok(Cassette {
+        request,
+        response: CassetteResponse {
+            body,
+            retry_after,
+            status: status.as_u16(),
+        },
+    })
+}
+
+fn initialize_response<T>(response: CassetteResponse, vendor: &Vendor) -> Result<T, IntegrationError> where
     T: Serialize + for<'de> Deserialize<'de>,
 {
-    let log: String = format!(
-        "Response from {}\nStatus: {}\nHeaders : {:?}\n",
-        url,
-        response.status(),
-        response.headers().clone()
+    let status: StatusCode = StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let retry_after: Option<u64> = response.retry_after;
+    let response_text: String = response.body;
+    log!(
+        target: get_target(vendor),
+        if status.is_success() { Level::Debug } else { Level::Warn },
+        "Body : {}",
+        redact_body(&response_text)
     );
-    let response_text: String = response.text().await.unwrap();
-    info!("{}Body : {}", log, response_text);
 
-    match serde_json::from_str(&response_text) {
-        Ok(data) => Some(data),
-        Err(err) => {
-            error!("{:?}", err);
-            None
-        }
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(IntegrationError::RateLimit {
+            body: redact_body(&response_text),
+            retry_after,
+        });
     }
+
+    if !status.is_success() {
+        return Err(IntegrationError::Status {
+            body: redact_body(&response_text),
+            status: status.as_u16(),
+        });
+    }
+
+    serde_json::from_str(&response_text).map_err(|err| IntegrationError::Parse {
+        body: redact_body(&response_text),
+        error: err.to_string(),
+    })
 }
 
-pub async fn get_request<T>(client: &Client, url: &str) -> Option<T>
+async fn execute<T, F>(vendor: &Vendor, retry: u32, request: F) -> Result<T, IntegrationError>
 where
     T: Serialize + for<'de> Deserialize<'de>,
+    F: Fn(&Client) -> RequestBuilder,
 {
-    match client.get(url).send().await {
-        Ok(response) => initialize_response(response, url).await,
-        Err(err) => {
-            error!("{:?}", err);
-            None
+    let circuit_breaker: &CircuitBreaker = CircuitBreaker::instance();
+
+    if let Err(err) = circuit_breaker.allow(vendor) {
+        record_metrics(vendor, None, Some(&err));
+
+        return Err(err);
+    }
+
+    let backoff_max: Duration = Duration::from_millis(AppConfig::instance().integration.backoff_max);
+    let mut attempt: u32 = 0;
+
+    loop {
+        let started: Instant = Instant::now();
+        let result: Result<T, IntegrationError> = match request(get_client())
+            .timeout(get_timeout(vendor))
+            .build()
+        {
+            Ok(request) => send(vendor, request).await,
+            Err(err) => {
+                let err: reqwest::Error = err.without_url();
+                error!(target: get_target(vendor), "{:?}", err);
+                circuit_breaker.release(vendor);
+
+                return Err(IntegrationError::Network(err.to_string()));
+            }
+        };
+
+        record_metrics(vendor, Some(started.elapsed()), result.as_ref().err());
+
+        match result {
+            Err(err) if err.is_retryable() => {
+                if attempt >= retry || get_retry_after_delay(&err).is_some_and(|retry_after| retry_after > backoff_max) {
+                    circuit_breaker.record_failure(vendor, &err);
+
+                    return Err(err);
+                }
+
+                let backoff: Duration = get_backoff(attempt, &err).min(backoff_max);
+
+                attempt += 1;
+                warn!(
+                    target: get_target(vendor),
+                    "Request to {:?} failed, retrying in {} ms ({}/{}): {}",
+                    vendor,
+                    backoff.as_millis(),
+                    attempt,
+                    retry,
+                    err
+                );
+
+                sleep(backoff).await;
+            }
+            Err(err) => {
+                circuit_breaker.record_response(vendor);
+
+                return Err(err);
+            }
+            Ok(response) => {
+                circuit_breaker.record_success(vendor);
+
+                return Ok(response);
+            }
         }
     }
 }
 
-pub async fn post_request<T, U>(
-    client: &Client,
+fn record_metrics(vendor: &Vendor, duration: Option<Duration>, err: Option<&IntegrationError>) {
+    let metrics: &Metrics = Metrics::instance();
+    let provider: &str = get_name(vendor);
+
+    if let Some(duration) = duration {
+        metrics.increment(
+            &Metric::IntegrationRequests,
+            &[("provider", provider), ("status", if err.is_some() { "error" } else { "success" })],
+            1.0,
+        );
+        metrics.observe(&Metric::IntegrationRequestDuration, &[("provider", provider)], duration.as_secs_f64());
+    }
+
+    if let Some(err) = err {
+        metrics.increment(&Metric::IntegrationErrors, &[("provider", provider), ("kind", err.get_kind())], 1.0);
+    }
+}
+
+fn read_cassette(vendor: &Vendor, request: &str) -> Result<Cassette, IntegrationError> {
+    let path: PathBuf = get_cassette_path(vendor, request);
+
+    match read_to_string(&path) {
+        Ok(file) => {
+            info!(target: get_target(vendor), "Replay from {}", path.display());
+
+            serde_json::from_str(&file).map_err(|err| IntegrationError::Cassette(format!("{}: {}", path.display(), err)))
+        }
+        Err(_) => Err(IntegrationError::Cassette(format!("{} not found for {}", path.display(), request))),
+    }
+}
+
+fn write_cassette(vendor: &Vendor, cassette: &Cassette) {
+    let path: PathBuf = get_cassette_path(vendor, &cassette.request);
+
+    if let Some(directory) = path.parent() {
+        let _ = create_dir_all(directory);
+    }
+
+    match write(&path, serde_json::to_string_pretty(cassette).unwrap_or_default()) {
+        Ok(_) => info!(target: get_target(vendor), "Recorded to {}", path.display()),
+        Err(err) => error!(target: get_target(vendor), "{:?}", err),
+    }
+}
+
+async fn send<T>(vendor: &Vendor, request: Request) -> Result<T, IntegrationError>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    info!(
+        target: get_target(vendor),
+        "Request to {} {}\nHeaders : {}",
+        request.method(),
+        redact_url(request.url().as_str()),
+        redact_headers(request.headers())
+    );
+
+    let body: Option<&[u8]> = request.body().and_then(|body| body.as_bytes());
+
+    if let Some(body) = body {
+        debug!(
+            target: get_target(vendor),
+            "Body : {}",
+            redact_body(&String::from_utf8_lossy(body))
+        );
+    }
+
+    let normalized: String = normalize_request(request.method().as_str(), request.url().as_str(), body);
+
+    let cassette: Cassette = match AppConfig::instance().integration.mode {
+        IntegrationMode::Replay => read_cassette(vendor, &normalized)?,
+        mode => {
+            let cassette: Cassette = match get_client().execute(request).await {
+                Ok(response) => initialize_cassette(response, vendor, normalized).await?,
+                Err(err) => {
+                    let err: reqwest::Error = err.without_url();
+                    error!(target: get_target(vendor), "{:?}", err);
+                    return Err(IntegrationError::Network(err.to_string()));
+                }
+            };
+
+            if mode == IntegrationMode::Record {
+                write_cassette(vendor, &cassette);
+            }
+
+            cassette
+        }
+    };
+
+    initialize_response(cassette.response, vendor)
+}
+
+pub async fn get_request<T>(vendor: &Vendor, url: &str) -> Result<T, IntegrationError>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    execute(vendor, AppConfig::instance().integration.retry, |client| client.get(url)).await
+}
+
+pub async fn post_request<T, U, F>(
+    vendor: &Vendor,
     url: &str,
     headers: &HeaderMap,
-    body: &T,
-) -> Option<U>
+    body: F,
+    idempotent: bool,
+) -> Result<U, IntegrationError>
 where
     T: Serialize + for<'de> Deserialize<'de>,
     U: Serialize + for<'de> Deserialize<'de>,
+    F: Fn() -> T,
 {
-    match client
-        .post(url)
-        .headers(headers.clone())
-        .body(serde_json::to_string(body).unwrap())
-        .send()
-        .await
-    {
-        Ok(response) => initialize_response(response, url).await,
-        Err(err) => {
-            error!("{:?}", err);
-            None
-        }
-    }
+    let retry: u32 = if idempotent { AppConfig::instance().integration.retry } else { 0 };
+
+    execute(vendor, retry, |client| {
+        client
+            .post(url)
+            .headers(headers.clone())
+            .body(serde_json::to_string(&body()).unwrap_or_default())
+    })
+    .await
+}
+
+pub async fn status(
+    _client: AuthenticatedClient,
+    _data: web::Json<IntegrationStatusRequest>,
+) -> impl Responder {
+    let response: IntegrationStatusResponse = IntegrationStatusResponse {
+        circuits: CircuitBreaker::instance().get_circuits(),
+        response: "Integration status retrieved successfully.".to_string(),
+        result: true,
+    };
+
+    HttpResponse::Ok().json(response)
 }
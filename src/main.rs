@@ -1,48 +1,89 @@
 use actix_cors::Cors;
 use actix_files::Files;
+use actix_web::http::header;
 use actix_web::{middleware, web, App, HttpServer};
 use log::error;
 use mongodb::{Client, Database};
-use rizky_pratama_tan_api::core::schedulers::scheduler_eodhd_intraday_1m;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::authorization::enums::Permission;
+use rizky_pratama_tan_api::core::authorization::middleware::Authorization;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::database::services::initialize_client;
+use rizky_pratama_tan_api::core::error::services::json_error_handler;
+use rizky_pratama_tan_api::core::metrics::middleware::RequestMetrics;
+use rizky_pratama_tan_api::core::rate_limit::enums::RateLimitKey;
+use rizky_pratama_tan_api::core::rate_limit::middleware::RateLimit;
+use rizky_pratama_tan_api::core::schedulers::scheduler_nonce_purge;
 use rizky_pratama_tan_api::core::services::{initialize_discord, initialize_env, initialize_log, initialize_task};
 use rizky_pratama_tan_api::site;
-use rizky_pratama_tan_api::{core, trading};
-use std::env;
+use rizky_pratama_tan_api::user::avatar::services::get_avatar_directory;
+use rizky_pratama_tan_api::{client, core, integration, trading, user};
+use std::fs::create_dir_all;
+use std::io::{Error, ErrorKind};
 use std::sync::Arc;
 use tera::Tera;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
 	initialize_env();
-	initialize_log();
+
+	let config: AppConfig = match AppConfig::load() {
+		Ok(config) => config,
+		Err(errors) => {
+			for err in &errors {
+				eprintln!("{}", err);
+			}
+
+			return Err(Error::new(ErrorKind::InvalidInput, "Invalid configuration."));
+		}
+	};
+	AppConfig::initialize(&config);
+
+	if let Err(err) = initialize_log(&config) {
+		eprintln!("{}", err);
+
+		return Err(Error::other("Invalid log configuration."));
+	}
+
 	initialize_discord();
 
-	let mongodb_client: Option<Client> = match Client::with_uri_str(&env::var("DATABASE_CONNECTION_STRING").unwrap_or_default()).await {
-		Ok(client) => Some(client),
+	let mongodb_client: Client = match initialize_client(&config.database.connection_string).await {
+		Ok(client) => client,
 		Err(err) => {
 			error!("{:?}", err);
 
-			None
+			return Err(Error::new(ErrorKind::InvalidInput, "Invalid database connection string."));
 		}
 	};
 
-	let database: Arc<Database> = Arc::new(
-		mongodb_client.unwrap().database(&env::var("DATABASE_NAME").unwrap_or_default()),
-	);
+	let database: Arc<Database> = Arc::new(mongodb_client.database(&config.database.name));
 	let database_arc: Arc<Database> = Arc::clone(&database);
 
-	scheduler_eodhd_intraday_1m(&database_arc.clone());
+	scheduler_nonce_purge();
 
 	initialize_task(&database_arc.clone()).await;
 
-	let server_address: String = env::var("SERVER_ADDRESS").unwrap_or_default();
-	let tera: Tera = Tera::new("resources/templates/**/*").unwrap();
+	if let Err(err) = create_dir_all(get_avatar_directory()) {
+		error!("{:?}", err);
+	}
+
+	let avatar_cache_ttl: u64 = config.user.avatar.cache_ttl;
+	let server_address: String = config.server.address.clone();
+	let tera: Tera = match Tera::new("resources/templates/**/*") {
+		Ok(tera) => tera,
+		Err(err) => {
+			error!("{:?}", err);
+
+			return Err(Error::other("Failed to load templates."));
+		}
+	};
 
 	HttpServer::new(move || {
 		let database_clone: Arc<Database> = Arc::clone(&database_arc);
 
 		App::new().wrap(
 			Cors::default().allow_any_origin().allow_any_method().allow_any_header().max_age(3600),
-		).wrap(middleware::Logger::default()).app_data(web::Data::new(tera.clone())).app_data(web::Data::from(database_clone)).service(Files::new("/resources", "./resources").show_files_listing()).service(web::scope("/site").configure(site::routes::config)).service(web::scope("/trading").configure(trading::routes::config)).route("/", web::get().to(core::base::services::index))
+		).wrap(middleware::Logger::default()).wrap(RequestMetrics).app_data(web::JsonConfig::default().error_handler(json_error_handler)).app_data(web::Data::new(tera.clone())).app_data(web::Data::new(config.clone())).app_data(web::Data::from(database_clone)).service(Files::new("/resources", "./resources").show_files_listing()).service(web::scope("/avatar").wrap(middleware::DefaultHeaders::new().add((header::CACHE_CONTROL, format!("public, max-age={}", avatar_cache_ttl)))).service(Files::new("", get_avatar_directory()))).service(web::scope("/client").wrap(Authorization::new(Permission::ManageClients)).wrap(RateLimit::new("client", RateLimitKey::Client)).wrap(Authentication::new(Access::Private)).wrap(RateLimit::new("client", RateLimitKey::Ip)).configure(client::routes::config)).service(web::scope("/diagnostics").wrap(Authorization::new(Permission::ViewDiagnostics)).wrap(RateLimit::new("diagnostics", RateLimitKey::Client)).wrap(Authentication::new(Access::Public)).wrap(RateLimit::new("diagnostics", RateLimitKey::Ip)).configure(core::health::routes::config)).service(web::scope("/history").wrap(RateLimit::new("history", RateLimitKey::Client)).wrap(Authentication::new(Access::Public)).wrap(RateLimit::new("history", RateLimitKey::Ip)).configure(core::history::routes::config)).service(web::scope("/integration").wrap(RateLimit::new("integration", RateLimitKey::Client)).wrap(Authentication::new(Access::Private)).wrap(RateLimit::new("integration", RateLimitKey::Ip)).configure(integration::routes::config)).service(web::scope("/site").wrap(RateLimit::new("site", RateLimitKey::Client)).wrap(Authentication::new(Access::Public)).wrap(RateLimit::new("site", RateLimitKey::Ip)).configure(site::routes::config)).service(web::scope("/user").wrap(RateLimit::new("user", RateLimitKey::Client)).wrap(Authentication::new(Access::Public)).wrap(RateLimit::new("user", RateLimitKey::Ip)).configure(user::routes::config)).service(web::scope("/trading").wrap(RateLimit::new("trading", RateLimitKey::Client)).wrap(Authentication::new(Access::Private)).wrap(RateLimit::new("trading", RateLimitKey::Ip)).configure(trading::routes::config)).route("/health", web::get().to(core::health::services::health)).route("/health/", web::get().to(core::health::services::health)).route("/ready", web::get().to(core::health::services::ready)).route("/ready/", web::get().to(core::health::services::ready)).route("/metrics", web::get().to(core::metrics::services::metrics)).route("/metrics/", web::get().to(core::metrics::services::metrics)).route("/", web::get().to(core::base::services::index))
 	}).bind(server_address)?.run().await
 }
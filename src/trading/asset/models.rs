@@ -22,6 +22,7 @@ pub struct Asset {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AssetSync {
+	pub error: Option<String>,
 	#[serde(with = "chrono_datetime_as_bson_datetime")]
 	pub last: DateTime<Utc>,
 	pub symbol: String,
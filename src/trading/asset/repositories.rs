@@ -1,5 +1,5 @@
 use crate::core::database::models::UserReference;
-use crate::core::database::services::timestamp;
+use crate::core::database::services::{archive, timestamp};
 use crate::trading::asset::models::Asset;
 use bson::oid::ObjectId;
 use bson::{doc, Document};
@@ -32,6 +32,27 @@ pub async fn create_index(database: &Database) {
 	}
 }
 
+pub async fn find(database: &Database) -> Option<Vec<Asset>> {
+	match database
+		.collection::<Asset>("trading_asset")
+		.find(doc! {})
+		.sort(doc! {"ticker": 1})
+		.await
+	{
+		Ok(cursor) => match cursor.try_collect().await {
+			Ok(asset) => Some(asset),
+			Err(err) => {
+				error!("{:?}", err);
+				None
+			}
+		},
+		Err(err) => {
+			error!("{:?}", err);
+			None
+		}
+	}
+}
+
 pub async fn find_by_sync_synchronized(
 	database: &Database,
 	sync_synchronized: &bool,
@@ -106,11 +127,6 @@ pub async fn log_insert_one(
 ) -> Result<InsertOneResult, Error> {
 	database
 		.collection::<Document>("trading_asset_log_data")
-		.insert_one(timestamp(
-			data,
-			user,
-			&true,
-			Some("trading_asset_id".to_string()),
-		))
+		.insert_one(archive(data, user, "trading_asset_id"))
 		.await
 }
@@ -1,8 +1,10 @@
+use crate::core::authorization::enums::Permission;
+use crate::core::authorization::middleware::Authorization;
 use crate::trading::asset;
 use actix_web::{web, HttpResponse};
 
 pub fn config(config: &mut web::ServiceConfig) {
 	config.service(
-		web::resource("/").route(web::post().to(asset::services::create)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+		web::resource("/").wrap(Authorization::new(Permission::ManageAssets)).route(web::post().to(asset::services::create)).route(web::head().to(HttpResponse::MethodNotAllowed)),
 	);
 }
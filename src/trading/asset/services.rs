@@ -1,57 +1,34 @@
-use crate::core::authentication::enums::Access;
-use crate::core::authentication::services::authenticate;
-use crate::core::base::models::BaseResponse;
+use crate::core::authentication::models::AuthenticatedUser;
 use crate::core::database::models::CreateResponse;
-use crate::core::database::services::error_message;
+use crate::core::database::services::database_error;
+use crate::core::error::enums::AppError;
 use crate::trading::asset::models::{Asset, SaveAssetRequest};
 use crate::trading::asset::repositories::insert_one;
-use crate::user::log::models::UserLog;
-use crate::user::log::repositories::find_one_by_authentication;
-use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use actix_web::{web, HttpResponse};
+use mongodb::results::InsertOneResult;
 use mongodb::Database;
 
 pub async fn create(
-    request: HttpRequest,
+    user: AuthenticatedUser,
     database: web::Data<Database>,
     data: web::Json<SaveAssetRequest>,
-) -> impl Responder {
-    let mut response: CreateResponse = CreateResponse::default();
+) -> Result<HttpResponse, AppError> {
+    let result: InsertOneResult = insert_one(
+        database.get_ref(),
+        &Asset {
+            provider: data.provider.clone(),
+            status: data.clone().status,
+            ticker: data.clone().symbol,
+            ..Default::default()
+        },
+        Some(user.log.user),
+    )
+    .await
+    .map_err(|error| database_error(error, "name"))?;
 
-    let authentication: BaseResponse = authenticate(request, &Access::Private, &data.clone());
-
-    if authentication.result {
-        let log: Option<UserLog> =
-            find_one_by_authentication(database.get_ref(), &data.authentication).await;
-
-        if !log.is_none() {
-            match insert_one(
-                database.get_ref(),
-                &Asset {
-                    provider: data.provider.clone(),
-                    status: data.clone().status,
-                    ticker: data.clone().symbol,
-                    ..Default::default()
-                },
-                Some(log.unwrap_or_default().user),
-            )
-            .await
-            {
-                Ok(_) => {
-                    response.response = "Asset has been created successfully.".to_string();
-                    response.result = true;
-                }
-                Err(error) => {
-                    for key in vec!["name"] {
-                        response.response = error_message(error.clone(), key);
-                    }
-                }
-            };
-        } else {
-            response.response = "Abnormal request detected.".to_string();
-        }
-    } else {
-        return HttpResponse::Unauthorized().json(authentication);
-    }
-
-    HttpResponse::Ok().json(response)
+    Ok(HttpResponse::Ok().json(CreateResponse {
+        id: result.inserted_id.as_object_id().unwrap_or_default(),
+        response: "Asset has been created successfully.".to_string(),
+        result: true,
+    }))
 }
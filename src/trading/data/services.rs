@@ -1,4 +1,9 @@
+use crate::core::base::enums::Status;
 use crate::core::base::models::BaseResponse;
+use crate::core::health::services::HealthMonitor;
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
+use crate::integration::enums::IntegrationError;
 use crate::integration::massive::enums::Timespan;
 use crate::integration::massive::models::{AggregateTickerRequest, AggregateTickerResponse};
 use crate::integration::massive::services::aggregate_ticker;
@@ -9,7 +14,7 @@ use crate::trading::data::models::{Data, DataPrice, DataSyncArc};
 use crate::trading::data::repositories::{find, find_one, insert_one, replace_one};
 use bson::doc;
 use chrono::{DateTime, Duration, Utc};
-use log::info;
+use log::{error, info, warn};
 use mongodb::Database;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -22,7 +27,7 @@ pub async fn analyze(database: &Database, asset: &Asset) -> BaseResponse {
 
 	let raw_data_vec: Option<Vec<Data>> = find(database, &asset.ticker).await;
 
-	if !raw_data_vec.is_none() {
+	if raw_data_vec.is_some() {
 		let data_vec: Vec<Data> = raw_data_vec.unwrap_or_default();
 
 		for (index, data) in data_vec.iter().enumerate() {
@@ -79,13 +84,38 @@ pub async fn analyze(database: &Database, asset: &Asset) -> BaseResponse {
 	response
 }
 
+pub fn get_data(response: &AggregateTickerResponse, timeframe: &Timeframe) -> Vec<Data> {
+	response
+		.results
+		.iter()
+		.map(|result| {
+			let mut data: Data = Data {
+				datetime: result.timestamp,
+				price: DataPrice {
+					close: result.close,
+					high: result.high,
+					low: result.low,
+					open: result.open,
+				},
+				timeframe: timeframe.clone(),
+				volume: result.volume,
+				..Default::default()
+			};
+			data.change.amount = data.price.close - data.price.open;
+			data.change.percentage = data.change.amount / data.price.open * 100f64;
+
+			data
+		})
+		.collect()
+}
+
 pub async fn copy(
 	database: &Database,
 	asset: &Asset,
 	timeframe: &Timeframe,
 	from: Option<DateTime<Utc>>,
 	to: Option<DateTime<Utc>>,
-) -> BaseResponse {
+) -> Result<BaseResponse, IntegrationError> {
 	let mut result: BaseResponse = BaseResponse::default();
 
 	let mut params: AggregateTickerRequest = AggregateTickerRequest {
@@ -95,61 +125,65 @@ pub async fn copy(
 		..Default::default()
 	};
 
-	if !from.is_none() {
+	if from.is_some() {
 		params.from = from.unwrap_or_default().format("%Y-%m-%d").to_string();
 	}
 
-	if !to.is_none() {
+	if to.is_some() {
 		params.to = to.unwrap_or_default().format("%Y-%m-%d").to_string();
 	}
 
-	let response: Option<AggregateTickerResponse> = aggregate_ticker(&params).await;
+	let response: AggregateTickerResponse = aggregate_ticker(&params).await?;
+	HealthMonitor::instance().record_sync(&asset.ticker);
+	let mut inserted: u64 = 0;
 
-	if !response.is_none() {
-		for result in response.unwrap_or_default().results {
-			let mut data: Data = Data {
-				datetime: result.timestamp,
-				price: DataPrice {
-					close: result.close,
-					high: result.high,
-					low: result.low,
-					open: result.open,
-				},
-				timeframe: timeframe.clone(),
-				volume: result.volume,
-				..Default::default()
-			};
-			data.change.amount = data.price.close - data.price.open;
-			data.change.percentage = data.change.amount / data.price.open * 100f64;
-
-			let _ = insert_one(database, &asset.ticker.clone(), &data, None).await;
+	for data in get_data(&response, timeframe) {
+		if insert_one(database, &asset.ticker.clone(), &data, None).await.is_ok() {
+			inserted += 1;
 		}
-
-		result.response = "Trading data has been copied successfully.".to_string();
-		result.result = true;
-	} else {
-		result.response = "Failed to retrieve trading data.".to_string();
 	}
 
-	result
+	Metrics::instance().increment(&Metric::TradingCandlesInserted, &[("asset", &asset.ticker)], inserted as f64);
+
+	result.response = "Trading data has been copied successfully.".to_string();
+	result.result = true;
+
+	Ok(result)
 }
 
 pub async fn get_realtime(
 	database: &Database,
 	asset: &Asset,
 	timeframe: &Timeframe,
-) -> BaseResponse {
-	let to: DateTime<Utc> = Utc::now();
+) -> Result<BaseResponse, IntegrationError> {
+	get_realtime_at(database, asset, timeframe, Utc::now()).await
+}
+
+pub async fn get_realtime_at(
+	database: &Database,
+	asset: &Asset,
+	timeframe: &Timeframe,
+	to: DateTime<Utc>,
+) -> Result<BaseResponse, IntegrationError> {
 	let from: DateTime<Utc> = to - Duration::hours(6);
-	let response: BaseResponse = copy(database, asset, timeframe, Some(from), Some(to)).await;
 
-	if response.result {
-		let mut new_asset: Asset = asset.clone();
-		new_asset.sync.last = to;
-		let _ = replace_one_asset(database, &new_asset, None).await;
-	}
+	match copy(database, asset, timeframe, Some(from), Some(to)).await {
+		Ok(response) => {
+			let mut new_asset: Asset = asset.clone();
+			new_asset.sync.error = None;
+			new_asset.sync.last = to;
+			let _ = replace_one_asset(database, &new_asset, None).await;
 
-	response
+			Ok(response)
+		}
+		Err(err) => {
+			if err.is_misconfigured() {
+				update_error(database, asset, &err).await;
+			}
+
+			Err(err)
+		}
+	}
 }
 
 pub fn get_interval(timeframe: &Timeframe) -> String {
@@ -215,65 +249,131 @@ pub fn sync(database: &Database, asset: Asset, timeframe: Timeframe) -> BaseResp
 	});
 
 	task::spawn(async move {
-		let scheduler: Arc<Mutex<JobScheduler>> =
-			Arc::new(Mutex::new(JobScheduler::new().await.unwrap()));
+		let name: String = format!("trading_data_sync_{}", data_task.asset.ticker);
+		let scheduler: Arc<Mutex<JobScheduler>> = match JobScheduler::new().await {
+			Ok(scheduler) => Arc::new(Mutex::new(scheduler)),
+			Err(err) => {
+				error!("Trading data sync for {} failed to create scheduler: {}", data_task.asset.ticker, err);
+
+				return;
+			}
+		};
 		let scheduler_arc: Arc<Mutex<JobScheduler>> = Arc::clone(&scheduler);
 		let data_task: Arc<DataSyncArc> = Arc::clone(&data_task);
+		let job_name: String = name.clone();
 
-		let job: JobLocked = Job::new_async("0 */1 * * * *", move |_uuid, _l| {
+		let job: JobLocked = match Job::new_async("0 */1 * * * *", move |_uuid, _l| {
 			let scheduler_arc: Arc<Mutex<JobScheduler>> = Arc::clone(&scheduler_arc);
 			let data_task: Arc<DataSyncArc> = Arc::clone(&data_task);
+			let job_name: String = job_name.clone();
 
 			Box::pin(async move {
+				let started: DateTime<Utc> = Utc::now();
 				let data_task: Arc<DataSyncArc> = Arc::clone(&data_task);
 
 				let asset: Option<Asset> =
 					find_one_by_id(&data_task.database, &data_task.asset.id).await;
 
-				if !asset.is_none() {
+				if asset.is_some() {
 					let asset: Asset = asset.unwrap_or_default();
 
 					let from: DateTime<Utc> = asset.sync.last;
 					let to: DateTime<Utc> = from + Duration::days(3);
-					let response: BaseResponse = copy(
+
+					match copy(
 						&data_task.database,
 						&asset,
 						&data_task.timeframe,
 						Some(from),
 						Some(to),
 					)
-						.await;
+						.await
+					{
+						Ok(response) => info!("{}", response.response),
+						Err(err) if err.is_retryable() => {
+							warn!("Trading data sync for {} will be retried: {}", asset.ticker, err);
+							HealthMonitor::instance().finish_job(&job_name, started);
+
+							return;
+						}
+						Err(err) if err.is_misconfigured() => {
+							update_error(&data_task.database, &asset, &err).await;
+							HealthMonitor::instance().stop_job(&job_name);
+
+							let _ = scheduler_arc.lock().await.shutdown().await;
+
+							return;
+						}
+						Err(err) if err.is_not_found() => info!("Trading data sync for {} found no data from {} to {}.", asset.ticker, from, to),
+						Err(err @ IntegrationError::Parse { .. }) => {
+							error!("Trading data sync for {} will be retried after an invalid response: {}", asset.ticker, err);
+							HealthMonitor::instance().finish_job(&job_name, started);
+
+							return;
+						}
+						Err(err) => {
+							error!("Trading data sync for {} will be retried from {} to {}: {}", asset.ticker, from, to, err);
+							HealthMonitor::instance().finish_job(&job_name, started);
 
-					info!("{}", response.response);
+							return;
+						}
+					}
 
 					update_last(&data_task.database, &asset, &to).await;
+					HealthMonitor::instance().finish_job(&job_name, started);
 
 					if to > Utc::now() {
 						analyze(&data_task.database, &asset).await;
+						HealthMonitor::instance().stop_job(&job_name);
 
 						let _ = scheduler_arc.lock().await.shutdown().await;
 					}
 				}
 			})
-		})
-			.unwrap();
+		}) {
+			Ok(job) => job,
+			Err(err) => {
+				error!("Trading data sync for {} failed to create job: {}", name, err);
+
+				return;
+			}
+		};
+
+		if let Err(err) = scheduler.lock().await.add(job).await {
+			error!("Trading data sync for {} failed to add job: {}", name, err);
 
-		scheduler.lock().await.add(job).await.unwrap();
-		scheduler.lock().await.start().await.unwrap();
+			return;
+		}
+
+		match scheduler.lock().await.start().await {
+			Ok(_) => HealthMonitor::instance().start_job(&name, "0 */1 * * * *"),
+			Err(err) => error!("Trading data sync for {} failed to start: {}", name, err),
+		}
 	});
 
 	response
 }
 
-async fn update_last(database: &Database, asset: &Asset, last: &DateTime<Utc>) {
+async fn update_error(database: &Database, asset: &Asset, err: &IntegrationError) {
+	error!("Asset {} is misconfigured: {}", asset.ticker, err);
+
+	let mut new_asset: Asset = asset.clone();
+	new_asset.status = Status::Inactive;
+	new_asset.sync.error = Some(err.to_string());
+
+	let _ = replace_one_asset(database, &new_asset, None).await;
+}
+
+pub async fn update_last(database: &Database, asset: &Asset, last: &DateTime<Utc>) {
 	let mut new_asset: Asset = asset.clone();
-	new_asset.sync.last = last.clone();
+	new_asset.sync.error = None;
+	new_asset.sync.last = *last;
 
-	if last.clone() > Utc::now() {
+	if *last > Utc::now() {
 		let data: Option<Data> =
 			find_one(database, &new_asset.ticker, doc! {"created.timestamp": -1}).await;
 
-		if !data.is_none() {
+		if data.is_some() {
 			new_asset.sync.last = data.unwrap_or_default().datetime;
 			new_asset.sync.synchronized = true;
 		}
@@ -0,0 +1,159 @@
+use crate::core::authentication::models::AuthenticatedUser;
+use crate::core::config::models::AppConfig;
+use crate::core::encryption::hmac::digest;
+use crate::core::error::enums::AppError;
+use crate::user::models::{User, UserAvatarResponse};
+use crate::user::repositories::{find_one_by_id, update_avatar};
+use actix_multipart::Multipart;
+use actix_web::{web, HttpResponse};
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, ImageReader, Limits};
+use log::error;
+use mongodb::Database;
+use serenity::futures::TryStreamExt;
+use std::fs::{create_dir_all, read_dir, remove_file, write};
+use std::io::Cursor;
+use std::path::Path;
+
+pub fn get_avatar_directory() -> String {
+	AppConfig::instance().user.avatar.directory
+}
+
+pub fn get_avatar_sizes() -> Vec<u32> {
+	let mut sizes: Vec<u32> = AppConfig::instance().user.avatar.sizes.into_iter().filter(|size| (16..=1024).contains(size)).collect();
+	sizes.sort_unstable();
+	sizes.dedup();
+
+	sizes
+}
+
+pub fn process_avatar(bytes: &[u8], sizes: &[u32]) -> Result<Vec<(u32, Vec<u8>)>, AppError> {
+	let mut reader: ImageReader<Cursor<&[u8]>> = ImageReader::new(Cursor::new(bytes))
+		.with_guessed_format()
+		.map_err(|_| AppError::Validation("Invalid image file.".to_string()))?;
+
+	if !matches!(reader.format(), Some(ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP)) {
+		return Err(AppError::Validation("Avatar must be a PNG, JPEG or WebP image.".to_string()));
+	}
+
+	let dimension: u32 = AppConfig::instance().user.avatar.max_dimension;
+	let mut limits: Limits = Limits::default();
+	limits.max_image_width = Some(dimension);
+	limits.max_image_height = Some(dimension);
+	reader.limits(limits);
+
+	let image: DynamicImage = reader.decode().map_err(|_| AppError::Validation("Invalid image file.".to_string()))?;
+	let mut images: Vec<(u32, Vec<u8>)> = Vec::new();
+
+	for size in sizes {
+		let mut buffer: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+		image
+			.resize_to_fill(*size, *size, FilterType::Lanczos3)
+			.write_to(&mut buffer, ImageFormat::Png)
+			.map_err(|err| AppError::Internal(format!("{:?}", err)))?;
+
+		images.push((*size, buffer.into_inner()));
+	}
+
+	Ok(images)
+}
+
+fn remove_avatar(directory: &str, avatar: &str) {
+	if avatar.is_empty() {
+		return;
+	}
+
+	if let Ok(entries) = read_dir(directory) {
+		for entry in entries.flatten().filter(|entry| entry.file_name().to_string_lossy().starts_with(&format!("{}_", avatar))) {
+			if let Err(err) = remove_file(entry.path()) {
+				error!("{:?}", err);
+			}
+		}
+	}
+}
+
+fn store_avatar(directory: &str, avatar: &str, images: &[(u32, Vec<u8>)]) -> Result<(), AppError> {
+	create_dir_all(directory).map_err(|err| AppError::Internal(format!("{:?}", err)))?;
+
+	for (size, bytes) in images {
+		write(Path::new(directory).join(format!("{}_{}.png", avatar, size)), bytes).map_err(|err| AppError::Internal(format!("{:?}", err)))?;
+	}
+
+	Ok(())
+}
+
+async fn read_avatar(mut multipart: Multipart) -> Result<Vec<u8>, AppError> {
+	let limit: usize = AppConfig::instance().user.avatar.max_size;
+
+	while let Ok(Some(mut field)) = multipart.try_next().await {
+		if field.name() != Some("avatar") {
+			continue;
+		}
+
+		let mut bytes: Vec<u8> = Vec::new();
+
+		while let Some(chunk) = field.try_next().await.map_err(|_| AppError::Validation("Invalid upload.".to_string()))? {
+			if bytes.len() + chunk.len() > limit {
+				return Err(AppError::Validation(format!("Avatar must not exceed {} KB.", limit / 1024)));
+			}
+
+			bytes.extend_from_slice(&chunk);
+		}
+
+		return Ok(bytes);
+	}
+
+	Err(AppError::Validation("Avatar file is required.".to_string()))
+}
+
+pub async fn upload(user: AuthenticatedUser, database: web::Data<Database>, multipart: Multipart) -> Result<HttpResponse, AppError> {
+	let account: User = find_one_by_id(database.get_ref(), &user.log.user.id)
+		.await
+		.ok_or(AppError::NotFound("User not found.".to_string()))?;
+	let bytes: Vec<u8> = read_avatar(multipart).await?;
+
+	let avatar: String = format!("{}_{}", account.id.to_hex(), &digest(&bytes)[..16]);
+	let directory: String = get_avatar_directory();
+
+	{
+		let avatar: String = avatar.clone();
+		let directory: String = directory.clone();
+
+		web::block(move || process_avatar(&bytes, &get_avatar_sizes()).and_then(|images| store_avatar(&directory, &avatar, &images)))
+			.await
+			.map_err(|err| AppError::Internal(format!("{:?}", err)))??;
+	}
+
+	if let Err(err) = update_avatar(database.get_ref(), &account.id, &avatar).await {
+		remove_avatar(&directory, &avatar);
+
+		return Err(AppError::from(err));
+	}
+
+	if account.avatar != avatar {
+		remove_avatar(&directory, &account.avatar);
+	}
+
+	Ok(HttpResponse::Ok().json(UserAvatarResponse {
+		avatar,
+		sizes: get_avatar_sizes(),
+		response: "Avatar has been uploaded successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn delete(user: AuthenticatedUser, database: web::Data<Database>) -> Result<HttpResponse, AppError> {
+	let account: User = find_one_by_id(database.get_ref(), &user.log.user.id)
+		.await
+		.ok_or(AppError::NotFound("User not found.".to_string()))?;
+
+	update_avatar(database.get_ref(), &account.id, "").await?;
+	remove_avatar(&get_avatar_directory(), &account.avatar);
+
+	Ok(HttpResponse::Ok().json(UserAvatarResponse {
+		response: "Avatar has been removed successfully.".to_string(),
+		result: true,
+		..Default::default()
+	}))
+}
@@ -1,3 +1,4 @@
 pub mod enums;
 pub mod models;
 pub mod repositories;
+pub mod services;
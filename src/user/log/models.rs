@@ -9,6 +9,8 @@ pub struct UserLog {
 	pub id: ObjectId,
 	pub authentication: String,
 	pub browser: UserLogBrowser,
+	#[serde(default)]
+	pub country: String,
 	pub device: UserLogDevice,
 	pub ip: String,
 	pub os: UserLogOs,
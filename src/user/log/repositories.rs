@@ -1,15 +1,69 @@
+use crate::core::database::models::UserReference;
+use crate::core::database::services::timestamp;
 use crate::user::log::models::UserLog;
-use bson::doc;
+use bson::oid::ObjectId;
+use bson::{doc, DateTime as BsonDateTime, Document};
+use chrono::{DateTime, Utc};
 use log::error;
-use mongodb::Database;
+use mongodb::error::Error;
+use mongodb::results::InsertOneResult;
+use mongodb::{Database, IndexModel};
+use serenity::futures::TryStreamExt;
+
+pub async fn create_index(database: &Database) {
+	let index_models: Vec<IndexModel> = vec![
+		IndexModel::builder().keys(doc! {"authentication": 1}).build(),
+		IndexModel::builder().keys(doc! {"user._id": 1, "created.timestamp": -1}).build(),
+	];
+
+	match database
+		.collection::<Document>("user_log")
+		.create_indexes(index_models)
+		.await
+	{
+		Ok(_) => {}
+		Err(err) => {
+			error!("{:?}", err)
+		}
+	}
+}
+
+pub async fn find_by_user(database: &Database, id: &ObjectId, since: DateTime<Utc>) -> Option<Vec<UserLog>> {
+	match database
+		.collection::<UserLog>("user_log")
+		.find(doc! {"user._id": id, "created.timestamp": {"$gte": BsonDateTime::from_chrono(since)}})
+		.sort(doc! {"created.timestamp": -1})
+		.await
+	{
+		Ok(cursor) => match cursor.try_collect().await {
+			Ok(log) => Some(log),
+			Err(err) => {
+				error!("{:?}", err);
+				None
+			}
+		},
+		Err(err) => {
+			error!("{:?}", err);
+			None
+		}
+	}
+}
 
 pub async fn find_one_by_authentication(
 	database: &Database,
 	authentication: &str,
 ) -> Option<UserLog> {
-	database.collection::<UserLog>("user_log").find_one(doc! {"authentication": authentication}).await.unwrap_or_else(|error| {
+	database.collection::<UserLog>("user_log").find_one(doc! {"authentication": authentication}).sort(doc! {"created.timestamp": -1}).await.unwrap_or_else(|error| {
 		error!("{:?}", error);
 
 		None
 	})
 }
+
+pub async fn insert_one(
+	database: &Database,
+	data: &UserLog,
+	user: Option<UserReference>,
+) -> Result<InsertOneResult, Error> {
+	database.collection::<Document>("user_log").insert_one(timestamp(data, user, &true, None)).await
+}
@@ -0,0 +1,126 @@
+use crate::user::log::enums::{Platform, Type};
+use crate::user::log::models::{UserLog, UserLogBrowser, UserLogDevice, UserLogOs};
+use crate::user::services::is_session_active;
+use std::collections::HashSet;
+
+const BOTS: [&str; 7] = ["bot", "crawler", "curl", "headless", "python-requests", "spider", "wget"];
+
+fn get_version(user_agent: &str, token: &str) -> Option<String> {
+	let start: usize = user_agent.find(token)? + token.len();
+
+	Some(
+		user_agent[start..]
+			.chars()
+			.take_while(|character| character.is_ascii_alphanumeric() || ['.', '_'].contains(character))
+			.collect::<String>()
+			.replace('_', "."),
+	)
+}
+
+pub fn parse_browser(user_agent: &str) -> UserLogBrowser {
+	let browsers: [(&str, &str); 9] = [
+		("Edg/", "Edge"),
+		("EdgA/", "Edge"),
+		("OPR/", "Opera"),
+		("SamsungBrowser/", "Samsung Internet"),
+		("FxiOS/", "Firefox"),
+		("Firefox/", "Firefox"),
+		("CriOS/", "Chrome"),
+		("Chrome/", "Chrome"),
+		("Version/", "Safari"),
+	];
+
+	for (token, family) in browsers {
+		if family == "Safari" && !user_agent.contains("Safari/") {
+			continue;
+		}
+
+		if let Some(version) = get_version(user_agent, token) {
+			return UserLogBrowser {
+				family: family.to_string(),
+				version,
+			};
+		}
+	}
+
+	UserLogBrowser {
+		family: "Other".to_string(),
+		version: String::new(),
+	}
+}
+
+pub fn parse_os(user_agent: &str) -> UserLogOs {
+	let (os, version): (&str, Option<String>) = if let Some(version) = get_version(user_agent, "Windows NT ") {
+		let version: &str = match version.as_str() {
+			"10.0" => "10",
+			"6.3" => "8.1",
+			"6.2" => "8",
+			"6.1" => "7",
+			version => version,
+		};
+
+		("Windows", Some(version.to_string()))
+	} else if user_agent.contains("iPhone") || user_agent.contains("iPad") || user_agent.contains("iPod") {
+		("iOS", get_version(user_agent, "OS "))
+	} else if user_agent.contains("Android") {
+		("Android", get_version(user_agent, "Android "))
+	} else if user_agent.contains("CrOS") {
+		("Chrome OS", None)
+	} else if user_agent.contains("Mac OS X") {
+		("Mac OS X", get_version(user_agent, "Mac OS X "))
+	} else if user_agent.contains("Linux") {
+		("Linux", None)
+	} else {
+		("Other", None)
+	};
+
+	UserLogOs {
+		os: os.to_string(),
+		version: version.unwrap_or_default(),
+	}
+}
+
+pub fn parse_device(user_agent: &str) -> UserLogDevice {
+	let lowercase: String = user_agent.to_lowercase();
+	let bot: bool = BOTS.iter().any(|bot| lowercase.contains(bot));
+	let tablet: bool = user_agent.contains("iPad")
+		|| user_agent.contains("Tablet")
+		|| (user_agent.contains("Android") && !user_agent.contains("Mobile"));
+	let mobile: bool = !tablet && (user_agent.contains("Mobi") || user_agent.contains("iPhone") || user_agent.contains("iPod"));
+	let pc: bool = !bot
+		&& !tablet
+		&& !mobile
+		&& ["Windows NT", "Macintosh", "CrOS", "X11", "Linux"].iter().any(|token| user_agent.contains(token));
+
+	UserLogDevice {
+		bot,
+		mobile,
+		pc,
+		tablet,
+		touch_capable: mobile || tablet || user_agent.contains("Touch"),
+	}
+}
+
+pub fn parse_user_agent(user_agent: &str) -> (UserLogBrowser, UserLogDevice, UserLogOs, Platform) {
+	let device: UserLogDevice = parse_device(user_agent);
+	let platform: Platform = if device.mobile || device.tablet {
+		Platform::Mobile
+	} else {
+		Platform::Desktop
+	};
+
+	(parse_browser(user_agent), device, parse_os(user_agent), platform)
+}
+
+pub fn get_active_sessions(logs: &[UserLog]) -> Vec<UserLog> {
+	let revoked: HashSet<&str> = logs
+		.iter()
+		.filter(|log| log._type == Type::Logout)
+		.map(|log| log.authentication.as_str())
+		.collect();
+
+	logs.iter()
+		.filter(|log| is_session_active(log) && !revoked.contains(log.authentication.as_str()))
+		.cloned()
+		.collect()
+}
@@ -1,5 +1,8 @@
+pub mod avatar;
 pub mod enums;
 pub mod log;
 pub mod models;
 pub mod repositories;
+pub mod routes;
+pub mod security;
 pub mod services;
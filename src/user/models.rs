@@ -1,7 +1,11 @@
 use crate::core::base::enums::{Country, Language, Sidebar, Status, Version};
 use crate::core::database::models::Timestamp;
 use crate::user::enums::{Gender, UserType};
+use crate::user::log::enums::Platform;
+use crate::user::log::models::{UserLogBrowser, UserLogDevice, UserLogOs};
 use bson::oid::ObjectId;
+use bson::serde_helpers::chrono_datetime_as_bson_datetime;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -17,6 +21,8 @@ pub struct User {
 	pub name: UserName,
 	pub password: UserPassword,
 	pub status: Status,
+	#[serde(default)]
+	pub totp: UserTotp,
 	#[serde(rename = "type")]
 	pub _type: UserType,
 	pub username: String,
@@ -34,6 +40,14 @@ pub struct UserAddress {
 	pub zip: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserAvatarResponse {
+	pub avatar: String,
+	pub response: String,
+	pub result: bool,
+	pub sizes: Vec<u32>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UserContact {
 	pub discord: String,
@@ -46,6 +60,26 @@ pub struct UserContact {
 	pub whatsapp: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserData {
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	pub address: UserAddress,
+	pub avatar: String,
+	pub contact: UserContact,
+	pub gender: Gender,
+	pub language: Language,
+	pub layout: UserLayout,
+	pub name: UserName,
+	pub status: Status,
+	pub totp: bool,
+	#[serde(rename = "type")]
+	pub _type: UserType,
+	pub username: String,
+	pub created: Timestamp,
+	pub modified: Timestamp,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UserLayout {
 	pub sidebar: Sidebar,
@@ -61,6 +95,222 @@ pub struct UserName {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UserPassword {
+	#[serde(default)]
+	pub attempt: u32,
+	#[serde(default, with = "chrono_datetime_as_bson_datetime")]
+	pub locked: DateTime<Utc>,
 	pub main: String,
 	pub recovery: String,
+	#[serde(default, with = "chrono_datetime_as_bson_datetime")]
+	pub recovery_expired: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserPasswordChangeRequest {
+	pub authentication: Option<String>,
+	pub current: String,
+	pub password: String,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserPasswordRecoverRequest {
+	pub email: String,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserPasswordResetRequest {
+	pub password: String,
+	pub recovery: String,
+	pub token: Option<String>,
+	pub username: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserListRequest {
+	pub authentication: Option<String>,
+	pub page: Option<u64>,
+	pub search: Option<String>,
+	pub size: Option<i64>,
+	pub status: Option<Status>,
+	pub token: Option<String>,
+	#[serde(rename = "type")]
+	pub _type: Option<UserType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserListResponse {
+	pub data: Vec<UserData>,
+	pub page: u64,
+	pub response: String,
+	pub result: bool,
+	pub size: i64,
+	pub total: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserLoginRequest {
+	pub code: Option<String>,
+	pub password: String,
+	pub platform: Option<Platform>,
+	pub remember: Option<bool>,
+	pub token: Option<String>,
+	pub username: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserLoginResponse {
+	pub authentication: String,
+	pub expired: Option<DateTime<Utc>>,
+	pub response: String,
+	pub result: bool,
+	pub totp: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserLogoutRequest {
+	pub authentication: Option<String>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserProfileAddress {
+	pub city: String,
+	pub country: String,
+	pub district: String,
+	pub state: String,
+	pub street: String,
+	pub zip: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserProfileRequest {
+	pub address: Option<UserProfileAddress>,
+	pub authentication: Option<String>,
+	pub contact: Option<UserContact>,
+	pub current: Option<String>,
+	pub gender: Option<Gender>,
+	pub language: Option<Language>,
+	pub layout: Option<UserLayout>,
+	pub name: Option<UserName>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserRegisterRequest {
+	pub email: String,
+	pub name: UserName,
+	pub password: String,
+	pub token: Option<String>,
+	pub username: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserResponse {
+	pub data: Option<UserData>,
+	pub response: String,
+	pub result: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserRoleRequest {
+	pub authentication: Option<String>,
+	pub token: Option<String>,
+	#[serde(rename = "type")]
+	pub _type: UserType,
+	pub username: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSaveRequest {
+	#[serde(default)]
+	pub address: UserAddress,
+	pub authentication: Option<String>,
+	#[serde(default)]
+	pub contact: UserContact,
+	#[serde(default)]
+	pub gender: Gender,
+	#[serde(default)]
+	pub language: Language,
+	#[serde(default)]
+	pub name: UserName,
+	pub password: Option<String>,
+	pub token: Option<String>,
+	#[serde(rename = "type")]
+	pub _type: Option<UserType>,
+	pub username: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSessionData {
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	pub browser: UserLogBrowser,
+	pub current: bool,
+	pub device: UserLogDevice,
+	pub expired: DateTime<Utc>,
+	pub ip: String,
+	pub os: UserLogOs,
+	pub platform: Platform,
+	pub created: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSessionListResponse {
+	pub data: Vec<UserSessionData>,
+	pub response: String,
+	pub result: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSessionRevokeRequest {
+	pub authentication: Option<String>,
+	#[serde(rename = "_id")]
+	pub id: Option<ObjectId>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserTotp {
+	pub enabled: bool,
+	pub last: i64,
+	pub recovery: Vec<String>,
+	pub secret: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserTotpEnrollResponse {
+	pub response: String,
+	pub result: bool,
+	pub secret: String,
+	pub uri: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserTotpRequest {
+	pub authentication: Option<String>,
+	pub code: String,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserTotpResetRequest {
+	pub authentication: Option<String>,
+	pub token: Option<String>,
+	pub username: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserTotpVerifyResponse {
+	pub recovery: Vec<String>,
+	pub response: String,
+	pub result: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserUsernameRequest {
+	pub authentication: Option<String>,
+	pub token: Option<String>,
+	pub username: String,
 }
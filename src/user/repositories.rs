@@ -1,23 +1,88 @@
 use crate::core::database::models::UserReference;
-use crate::core::database::services::timestamp;
-use crate::trading::asset::models::Asset;
-use crate::user::models::User;
+use crate::core::database::services::{archive, timestamp};
+use crate::user::models::{User, UserPassword, UserTotp};
 use bson::oid::ObjectId;
-use bson::{doc, Document};
+use bson::{doc, to_bson, DateTime as BsonDateTime, Document};
+use chrono::{DateTime, Utc};
 use log::error;
 use mongodb::error::Error;
-use mongodb::results::{InsertOneResult, UpdateResult};
-use mongodb::Database;
+use mongodb::results::{DeleteResult, InsertOneResult, UpdateResult};
+use mongodb::options::{IndexOptions, ReturnDocument};
+use mongodb::{Database, IndexModel};
+use serenity::futures::TryStreamExt;
 
-pub async fn find_one_by_id(database: &Database, id: &ObjectId) -> Option<Asset> {
-	database.collection::<Asset>("user").find_one(doc! {"_id": id}).await.unwrap_or_else(|err| {
+pub async fn create_index(database: &Database) {
+	let index_model = IndexModel::builder()
+		.keys(doc! {"username": 1})
+		.options(
+			IndexOptions::builder()
+				.unique(true)
+				.name(Some("username_unique_".to_string()))
+				.build(),
+		)
+		.build();
+	match database
+		.collection::<User>("user")
+		.create_index(index_model)
+		.await
+	{
+		Ok(_) => {}
+		Err(err) => {
+			error!("{:?}", err)
+		}
+	}
+}
+
+pub async fn count_documents(database: &Database, filter: Document) -> u64 {
+	database.collection::<User>("user").count_documents(filter).await.unwrap_or_else(|err| {
+		error!("{:?}", err);
+		0
+	})
+}
+
+pub async fn delete_one_by_id(database: &Database, id: &ObjectId) -> Result<DeleteResult, Error> {
+	database.collection::<User>("user").delete_one(doc! {"_id": id}).await
+}
+
+pub async fn find(database: &Database, filter: Document, skip: u64, limit: i64) -> Option<Vec<User>> {
+	match database
+		.collection::<User>("user")
+		.find(filter)
+		.sort(doc! {"username": 1})
+		.skip(skip)
+		.limit(limit)
+		.await
+	{
+		Ok(cursor) => match cursor.try_collect().await {
+			Ok(user) => Some(user),
+			Err(err) => {
+				error!("{:?}", err);
+				None
+			}
+		},
+		Err(err) => {
+			error!("{:?}", err);
+			None
+		}
+	}
+}
+
+pub async fn find_one_by_id(database: &Database, id: &ObjectId) -> Option<User> {
+	database.collection::<User>("user").find_one(doc! {"_id": id}).await.unwrap_or_else(|err| {
 		error!("{:?}", err);
 		None
 	})
 }
 
-pub async fn find_one_by_username(database: &Database, username: &str) -> Option<Asset> {
-	database.collection::<Asset>("user").find_one(doc! {"username": username}).await.unwrap_or_else(|err| {
+pub async fn find_one_by_email(database: &Database, email: &str) -> Option<User> {
+	database.collection::<User>("user").find_one(doc! {"contact.email": email}).await.unwrap_or_else(|err| {
+		error!("{:?}", err);
+		None
+	})
+}
+
+pub async fn find_one_by_username(database: &Database, username: &str) -> Option<User> {
+	database.collection::<User>("user").find_one(doc! {"username": username}).await.unwrap_or_else(|err| {
 		error!("{:?}", err);
 		None
 	})
@@ -39,10 +104,95 @@ pub async fn replace_one(
 	database.collection::<Document>("user").replace_one(doc! {"_id": data.id}, timestamp(data, user, &true, None)).await
 }
 
+pub async fn update_avatar(database: &Database, id: &ObjectId, avatar: &str) -> Result<UpdateResult, Error> {
+	database.collection::<Document>("user").update_one(doc! {"_id": id}, doc! {"$set": {"avatar": avatar}}).await
+}
+
+pub async fn update_password(
+	database: &Database,
+	id: &ObjectId,
+	recovery: &str,
+	password: &UserPassword,
+) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("user")
+		.update_one(
+			doc! {"_id": id, "password.recovery": recovery, "password.recovery_expired": {"$gt": BsonDateTime::from_chrono(Utc::now())}},
+			doc! {"$set": {"password": to_bson(password).unwrap_or_default()}},
+		)
+		.await
+}
+
+pub async fn update_password_attempt(database: &Database, id: &ObjectId) -> Result<Option<User>, Error> {
+	database
+		.collection::<User>("user")
+		.find_one_and_update(doc! {"_id": id}, doc! {"$inc": {"password.attempt": 1}})
+		.return_document(ReturnDocument::After)
+		.await
+}
+
+pub async fn update_password_locked(database: &Database, id: &ObjectId, locked: DateTime<Utc>) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("user")
+		.update_one(doc! {"_id": id}, doc! {"$set": {"password.attempt": 0, "password.locked": BsonDateTime::from_chrono(locked)}})
+		.await
+}
+
+pub async fn update_password_main(database: &Database, id: &ObjectId, current: &str, main: &str) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("user")
+		.update_one(doc! {"_id": id, "password.main": current}, doc! {"$set": {"password.main": main}})
+		.await
+}
+
+pub async fn update_password_recovery(
+	database: &Database,
+	id: &ObjectId,
+	recovery: &str,
+	recovery_expired: DateTime<Utc>,
+) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("user")
+		.update_one(
+			doc! {"_id": id},
+			doc! {"$set": {"password.recovery": recovery, "password.recovery_expired": BsonDateTime::from_chrono(recovery_expired)}},
+		)
+		.await
+}
+
+pub async fn reset_password_attempt(database: &Database, id: &ObjectId) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("user")
+		.update_one(doc! {"_id": id, "password.attempt": {"$gt": 0}}, doc! {"$set": {"password.attempt": 0}})
+		.await
+}
+
+pub async fn update_totp(
+	database: &Database,
+	id: &ObjectId,
+	totp: &UserTotp,
+) -> Result<UpdateResult, Error> {
+	database.collection::<Document>("user").update_one(doc! {"_id": id}, doc! {"$set": {"totp": to_bson(totp).unwrap_or_default()}}).await
+}
+
+pub async fn update_totp_last(database: &Database, id: &ObjectId, last: i64) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("user")
+		.update_one(doc! {"_id": id, "totp.last": {"$lt": last}}, doc! {"$set": {"totp.last": last}})
+		.await
+}
+
+pub async fn update_totp_recovery(database: &Database, id: &ObjectId, recovery: &str) -> Result<UpdateResult, Error> {
+	database
+		.collection::<Document>("user")
+		.update_one(doc! {"_id": id, "totp.recovery": recovery}, doc! {"$pull": {"totp.recovery": recovery}})
+		.await
+}
+
 pub async fn log_insert_one(
 	database: &Database,
 	data: &User,
 	user: Option<UserReference>,
 ) -> Result<InsertOneResult, Error> {
-	database.collection::<Document>("user_log_data").insert_one(timestamp(data, user, &true, Some("user_id".to_string()))).await
+	database.collection::<Document>("user_log_data").insert_one(archive(data, user, "user_id")).await
 }
@@ -0,0 +1,117 @@
+use crate::core::authorization::enums::Permission;
+use crate::core::authorization::middleware::Authorization;
+use crate::user::avatar;
+use crate::user::security;
+use crate::user::services::{
+	activate_totp, change_password, change_role, create, delete, disable_totp, enroll_totp, list, list_session, login, logout, profile,
+	recover_password, register, reset_password, reset_totp, revoke_session_by_id, revoke_session_other, suspend, update, update_profile, view,
+};
+use actix_web::{web, HttpResponse};
+
+pub fn config(config: &mut web::ServiceConfig) {
+	config.service(
+		web::resource("/login").route(web::post().to(login)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/login/").route(web::post().to(login)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/logout").route(web::post().to(logout)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/logout/").route(web::post().to(logout)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/register").route(web::post().to(register)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/register/").route(web::post().to(register)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/password/change").route(web::post().to(change_password)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/password/change/").route(web::post().to(change_password)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/password/recover").route(web::post().to(recover_password)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/password/recover/").route(web::post().to(recover_password)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/password/reset").route(web::post().to(reset_password)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/password/reset/").route(web::post().to(reset_password)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/activate").route(web::post().to(activate_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/activate/").route(web::post().to(activate_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/disable").route(web::post().to(disable_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/disable/").route(web::post().to(disable_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/enroll").route(web::post().to(enroll_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/enroll/").route(web::post().to(enroll_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/reset").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(reset_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/totp/reset/").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(reset_totp)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/role").wrap(Authorization::new(Permission::ManageRoles)).route(web::post().to(change_role)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/role/").wrap(Authorization::new(Permission::ManageRoles)).route(web::post().to(change_role)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/create").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(create)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/create/").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(create)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/delete").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(delete)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/delete/").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(delete)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/list").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/list/").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/suspend").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(suspend)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/suspend/").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(suspend)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/update").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(update)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/update/").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(update)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/view").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(view)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/view/").wrap(Authorization::new(Permission::ManageUsers)).route(web::post().to(view)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me").route(web::post().to(profile)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/").route(web::post().to(profile)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/update").route(web::post().to(update_profile)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/update/").route(web::post().to(update_profile)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/avatar").route(web::post().to(avatar::services::upload)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/avatar/").route(web::post().to(avatar::services::upload)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/avatar/delete").route(web::post().to(avatar::services::delete)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/avatar/delete/").route(web::post().to(avatar::services::delete)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/session").route(web::post().to(list_session)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/session/").route(web::post().to(list_session)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/session/revoke").route(web::post().to(revoke_session_by_id)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/session/revoke/").route(web::post().to(revoke_session_by_id)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/session/revoke/other").route(web::post().to(revoke_session_other)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/session/revoke/other/").route(web::post().to(revoke_session_other)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/security").route(web::post().to(security::services::list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/security/").route(web::post().to(security::services::list)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/security/acknowledge").route(web::post().to(security::services::acknowledge)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	).service(
+		web::resource("/me/security/acknowledge/").route(web::post().to(security::services::acknowledge)).route(web::head().to(HttpResponse::MethodNotAllowed)),
+	);
+}
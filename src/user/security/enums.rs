@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Debug, Default, Deserialize, EnumIter, Eq, PartialEq, Serialize)]
+pub enum UserSecurityEventType {
+	ImpossibleTravel,
+	NewDevice,
+	#[default]
+	NewIp,
+}
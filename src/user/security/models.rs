@@ -0,0 +1,45 @@
+use crate::core::database::models::{Timestamp, UserReference};
+use crate::user::log::models::{UserLogBrowser, UserLogOs};
+use crate::user::security::enums::UserSecurityEventType;
+use bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSecurityEvent {
+	#[serde(rename = "_id")]
+	pub id: ObjectId,
+	pub acknowledged: bool,
+	pub browser: UserLogBrowser,
+	pub country: String,
+	pub ip: String,
+	pub log: ObjectId,
+	pub os: UserLogOs,
+	pub previous_country: String,
+	#[serde(rename = "type")]
+	pub _type: UserSecurityEventType,
+	pub user: UserReference,
+	pub created: Timestamp,
+	pub modified: Timestamp,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSecurityEventAcknowledgeRequest {
+	pub authentication: Option<String>,
+	#[serde(rename = "_id")]
+	pub id: Option<ObjectId>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSecurityEventListRequest {
+	pub acknowledged: Option<bool>,
+	pub authentication: Option<String>,
+	pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UserSecurityEventListResponse {
+	pub data: Vec<UserSecurityEvent>,
+	pub response: String,
+	pub result: bool,
+}
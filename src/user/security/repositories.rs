@@ -0,0 +1,78 @@
+use crate::core::database::models::UserReference;
+use crate::core::database::services::timestamp;
+use crate::user::security::models::UserSecurityEvent;
+use bson::oid::ObjectId;
+use bson::{doc, Document};
+use log::error;
+use mongodb::error::Error;
+use mongodb::results::{InsertOneResult, UpdateResult};
+use mongodb::{Database, IndexModel};
+use serenity::futures::TryStreamExt;
+
+pub async fn create_index(database: &Database) {
+	let index_model: IndexModel = IndexModel::builder().keys(doc! {"user._id": 1, "created.timestamp": -1}).build();
+
+	match database
+		.collection::<Document>("user_security_event")
+		.create_index(index_model)
+		.await
+	{
+		Ok(_) => {}
+		Err(err) => {
+			error!("{:?}", err)
+		}
+	}
+}
+
+pub async fn find_by_user(database: &Database, id: &ObjectId, acknowledged: Option<bool>) -> Option<Vec<UserSecurityEvent>> {
+	let mut filter: Document = doc! {"user._id": id};
+
+	if let Some(acknowledged) = acknowledged {
+		filter.insert("acknowledged", acknowledged);
+	}
+
+	match database
+		.collection::<UserSecurityEvent>("user_security_event")
+		.find(filter)
+		.sort(doc! {"created.timestamp": -1})
+		.limit(100)
+		.await
+	{
+		Ok(cursor) => match cursor.try_collect().await {
+			Ok(event) => Some(event),
+			Err(err) => {
+				error!("{:?}", err);
+				None
+			}
+		},
+		Err(err) => {
+			error!("{:?}", err);
+			None
+		}
+	}
+}
+
+pub async fn insert_one(
+	database: &Database,
+	data: &UserSecurityEvent,
+	user: Option<UserReference>,
+) -> Result<InsertOneResult, Error> {
+	database.collection::<Document>("user_security_event").insert_one(timestamp(data, user, &true, None)).await
+}
+
+pub async fn update_acknowledged(
+	database: &Database,
+	user: &UserReference,
+	id: Option<ObjectId>,
+) -> Result<UpdateResult, Error> {
+	let mut filter: Document = doc! {"user._id": user.id, "acknowledged": false};
+
+	if let Some(id) = id {
+		filter.insert("_id", id);
+	}
+
+	database
+		.collection::<Document>("user_security_event")
+		.update_many(filter, doc! {"$set": {"acknowledged": true, "modified.user._id": user.id, "modified.user.username": &user.username}, "$currentDate": {"modified.timestamp": true}})
+		.await
+}
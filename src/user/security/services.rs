@@ -0,0 +1,153 @@
+use crate::core::authentication::models::AuthenticatedUser;
+use crate::core::base::models::BaseResponse;
+use crate::core::config::models::AppConfig;
+use crate::core::email::services::send;
+use crate::core::error::enums::AppError;
+use crate::integration::discord::services::send_security;
+use crate::user::log::enums::Type;
+use crate::user::log::models::UserLog;
+use crate::user::log::repositories::find_by_user as find_log_by_user;
+use crate::user::models::User;
+use crate::user::security::enums::UserSecurityEventType;
+use crate::user::security::models::{
+	UserSecurityEvent, UserSecurityEventAcknowledgeRequest, UserSecurityEventListRequest, UserSecurityEventListResponse,
+};
+use crate::user::security::repositories::{find_by_user, insert_one, update_acknowledged};
+use actix_web::{web, HttpResponse};
+use bson::oid::ObjectId;
+use chrono::{DateTime, TimeDelta, Utc};
+use log::{error, warn};
+use mongodb::results::UpdateResult;
+use mongodb::Database;
+
+pub fn detect_anomaly(history: &[UserLog], log: &UserLog, window: i64) -> Vec<(UserSecurityEventType, String)> {
+	let history: Vec<&UserLog> = history.iter().filter(|previous| previous._type == Type::Login && previous.id != log.id).collect();
+	let mut events: Vec<(UserSecurityEventType, String)> = Vec::new();
+
+	if history.is_empty() {
+		return events;
+	}
+
+	if let Some(previous) = history.iter().find(|previous| {
+		!log.country.is_empty()
+			&& !previous.country.is_empty()
+			&& previous.country != log.country
+			&& log.created.timestamp.signed_duration_since(previous.created.timestamp).num_seconds().abs() < window
+	}) {
+		events.push((UserSecurityEventType::ImpossibleTravel, previous.country.clone()));
+	}
+
+	if !history.iter().any(|previous| previous.ip == log.ip) {
+		events.push((UserSecurityEventType::NewIp, String::new()));
+	}
+
+	if !history.iter().any(|previous| previous.browser.family == log.browser.family && previous.os.os == log.os.os) {
+		events.push((UserSecurityEventType::NewDevice, String::new()));
+	}
+
+	events
+}
+
+fn get_description(event: &UserSecurityEvent) -> String {
+	match event._type {
+		UserSecurityEventType::ImpossibleTravel => format!(
+			"Login from {} shortly after a login from {} (IP {}).",
+			event.country, event.previous_country, event.ip
+		),
+		UserSecurityEventType::NewDevice => format!(
+			"Login from a new device: {} {} on {} {} (IP {}).",
+			event.browser.family, event.browser.version, event.os.os, event.os.version, event.ip
+		),
+		UserSecurityEventType::NewIp => format!("Login from a new IP address {}.", event.ip),
+	}
+}
+
+pub async fn inspect_login(database: Database, user: User, log: UserLog) {
+	let since: DateTime<Utc> = Utc::now() - TimeDelta::days(AppConfig::instance().user.security.history);
+	let history: Vec<UserLog> = match find_log_by_user(&database, &user.id, since).await {
+		Some(history) => history,
+		None => return,
+	};
+
+	let config: AppConfig = AppConfig::instance();
+	let mut descriptions: Vec<String> = Vec::new();
+
+	for (_type, previous_country) in detect_anomaly(&history, &log, AppConfig::instance().user.security.travel_window) {
+		let event: UserSecurityEvent = UserSecurityEvent {
+			id: ObjectId::new(),
+			browser: log.browser.clone(),
+			country: log.country.clone(),
+			ip: log.ip.clone(),
+			log: log.id,
+			os: log.os.clone(),
+			previous_country,
+			_type,
+			user: log.user.clone(),
+			..Default::default()
+		};
+
+		if let Err(err) = insert_one(&database, &event, Some(log.user.clone())).await {
+			error!("{:?}", err);
+
+			continue;
+		}
+
+		let description: String = get_description(&event);
+		warn!("Security event for {}. {}", user.username, description);
+
+		descriptions.push(description);
+	}
+
+	if descriptions.is_empty() {
+		return;
+	}
+
+	send_security(&format!("[{}] Security events for {}:\n{}", config.application.name, user.username, descriptions.join("\n"))).await;
+
+	if !user.contact.email.is_empty() {
+		send(
+			&config.email.user,
+			&user.contact.email,
+			&format!("[{}] Security Alert", config.application.name),
+			&format!(
+				"Hello {},\n\n{}\n\nIf this was you, you can acknowledge these events from your account. If not, change your password and revoke your other sessions immediately.",
+				user.username,
+				descriptions.join("\n")
+			),
+		)
+		.await;
+	}
+}
+
+pub async fn list(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserSecurityEventListRequest>,
+) -> Result<HttpResponse, AppError> {
+	let events: Vec<UserSecurityEvent> = find_by_user(database.get_ref(), &user.log.user.id, data.acknowledged)
+		.await
+		.ok_or(AppError::Internal("Failed to find security events.".to_string()))?;
+
+	Ok(HttpResponse::Ok().json(UserSecurityEventListResponse {
+		data: events,
+		response: "Security event list retrieved successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn acknowledge(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserSecurityEventAcknowledgeRequest>,
+) -> Result<HttpResponse, AppError> {
+	let result: UpdateResult = update_acknowledged(database.get_ref(), &user.log.user, data.id).await?;
+
+	if result.matched_count == 0 && data.id.is_some() {
+		return Err(AppError::NotFound("Security event not found.".to_string()));
+	}
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Security event has been acknowledged successfully.".to_string(),
+		result: true,
+	}))
+}
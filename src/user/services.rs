@@ -0,0 +1,1007 @@
+use crate::core::authentication::models::{AuthenticatedClient, AuthenticatedUser};
+use crate::core::authentication::services::{get_client_ip, is_trusted_proxy};
+use crate::core::authorization::enums::Permission;
+use crate::core::authorization::services::has_permission;
+use crate::core::base::enums::{Country, Status};
+use crate::core::base::models::BaseResponse;
+use crate::core::config::models::{AppConfig, UserSessionConfig};
+use crate::core::database::models::{CreateResponse, UserReference};
+use crate::core::database::services::database_error;
+use crate::core::email::services::send;
+use crate::core::encryption::hmac::{digest, is_equal};
+use crate::core::encryption::password::{hash_password, is_hashed, verify_password};
+use crate::core::encryption::totp::{generate_secret as generate_totp_secret, get_uri, verify_code};
+use crate::core::error::enums::AppError;
+use crate::core::metrics::enums::Metric;
+use crate::core::metrics::services::Metrics;
+use crate::user::enums::UserType;
+use crate::user::log::enums::{Platform, Type};
+use crate::user::log::models::{UserLog, UserLogBrowser, UserLogDevice, UserLogOs};
+use crate::user::log::repositories::{find_by_user, insert_one as insert_one_log};
+use crate::user::log::services::{get_active_sessions, parse_user_agent};
+use crate::user::models::{
+	User, UserAddress, UserContact, UserData, UserListRequest, UserListResponse, UserLoginRequest, UserLoginResponse,
+	UserLogoutRequest, UserPassword, UserPasswordChangeRequest, UserPasswordRecoverRequest, UserPasswordResetRequest,
+	UserProfileRequest, UserRegisterRequest, UserResponse, UserRoleRequest, UserSaveRequest, UserSessionData,
+	UserSessionListResponse, UserSessionRevokeRequest, UserTotp, UserTotpEnrollResponse, UserTotpRequest, UserTotpResetRequest,
+	UserTotpVerifyResponse, UserUsernameRequest,
+};
+use crate::user::repositories::{
+	count_documents, delete_one_by_id, find, find_one_by_email, find_one_by_id, find_one_by_username, insert_one,
+	log_insert_one, replace_one, reset_password_attempt, update_password, update_password_attempt, update_password_locked, update_password_main,
+	update_password_recovery, update_totp, update_totp_last, update_totp_recovery,
+};
+use crate::user::security::services::inspect_login;
+use actix_web::http::header::USER_AGENT;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use bson::oid::ObjectId;
+use bson::{doc, to_bson, Document, Regex};
+use chrono::{DateTime, TimeDelta, Utc};
+use log::{error, info, warn};
+use mongodb::results::{DeleteResult, InsertOneResult};
+use mongodb::Database;
+use rand::Rng;
+use regex::escape;
+use strum::IntoEnumIterator;
+use tokio::task;
+
+impl From<&User> for UserData {
+	fn from(user: &User) -> Self {
+		UserData {
+			id: user.id,
+			address: user.address.clone(),
+			avatar: user.avatar.clone(),
+			contact: user.contact.clone(),
+			gender: user.gender.clone(),
+			language: user.language.clone(),
+			layout: user.layout.clone(),
+			name: user.name.clone(),
+			status: user.status.clone(),
+			totp: user.totp.enabled,
+			_type: user._type.clone(),
+			username: user.username.clone(),
+			created: user.created.clone(),
+			modified: user.modified.clone(),
+		}
+	}
+}
+
+pub fn generate_authentication() -> String {
+	hex::encode(rand::thread_rng().r#gen::<[u8; 32]>())
+}
+
+pub fn get_session_expired(log: &UserLog) -> DateTime<Utc> {
+	let config: UserSessionConfig = AppConfig::instance().user.session;
+	let ttl: i64 = if log.remember { config.remember_ttl } else { config.ttl };
+
+	log.created.timestamp + TimeDelta::seconds(ttl)
+}
+
+pub fn get_country_code(request: &HttpRequest) -> String {
+	let header: String = AppConfig::instance().user.security.country_header;
+
+	if header.is_empty() || !is_trusted_proxy(request) {
+		return String::new();
+	}
+
+	request
+		.headers()
+		.get(header.as_str())
+		.and_then(|value| value.to_str().ok())
+		.map(|value| value.trim().to_uppercase())
+		.filter(|value| value.len() == 2 && value != "XX")
+		.unwrap_or_default()
+}
+
+pub fn is_session_active(log: &UserLog) -> bool {
+	log._type == Type::Login && get_session_expired(log) > Utc::now()
+}
+
+fn validate_password(password: &str) -> Option<String> {
+	if password.chars().count() < 8 {
+		return Some("Password must be at least 8 characters.".to_string());
+	}
+
+	None
+}
+
+fn validate_email(email: &str) -> Option<String> {
+	let email: Vec<&str> = email.split('@').collect();
+
+	if email.len() != 2 || email[0].is_empty() || !email[1].contains('.') {
+		return Some("Invalid email address.".to_string());
+	}
+
+	None
+}
+
+fn validate_username(username: &str) -> Option<String> {
+	let username_length: usize = username.chars().count();
+
+	if !(3..=32).contains(&username_length)
+		|| !username.chars().all(|character| character.is_ascii_alphanumeric() || ['_', '.', '-'].contains(&character))
+	{
+		return Some("Username must be 3 to 32 letters, numbers, dots, dashes or underscores.".to_string());
+	}
+
+	None
+}
+
+fn validate_phone(phone: &str) -> Option<String> {
+	let phone: String = phone.chars().filter(|character| !matches!(character, ' ' | '-' | '(' | ')')).collect();
+	let digits: &str = phone.strip_prefix('+').unwrap_or(&phone);
+
+	if !phone.is_empty() && (!(7..=15).contains(&digits.len()) || !digits.chars().all(|character| character.is_ascii_digit())) {
+		return Some("Invalid phone number.".to_string());
+	}
+
+	None
+}
+
+pub fn get_country(country: &str) -> Option<Country> {
+	Country::iter().find(|item| format!("{:?}", item).eq_ignore_ascii_case(country.trim()))
+}
+
+pub fn validate_profile(data: &UserProfileRequest) -> Option<String> {
+	if let Some(message) = data.contact.as_ref().and_then(|contact| validate_email(&contact.email).or_else(|| validate_phone(&contact.phone))) {
+		return Some(message);
+	}
+
+	if data.address.as_ref().is_some_and(|address| get_country(&address.country).is_none()) {
+		return Some("Invalid country.".to_string());
+	}
+
+	if data.name.as_ref().is_some_and(|name| name.first.trim().is_empty()) {
+		return Some("First name is required.".to_string());
+	}
+
+	None
+}
+
+fn validate_register(data: &UserRegisterRequest) -> Option<String> {
+	validate_username(&data.username)
+		.or_else(|| validate_password(&data.password))
+		.or_else(|| validate_email(&data.email))
+}
+
+pub fn get_user_filter(data: &UserListRequest) -> Document {
+	let mut filter: Document = doc! {};
+
+	if let Some(search) = data.search.as_ref().map(|search| search.trim()).filter(|search| !search.is_empty()) {
+		let pattern: Regex = Regex {
+			pattern: escape(search),
+			options: "i".to_string(),
+		};
+
+		filter.insert(
+			"$or",
+			["username", "contact.email", "name.first", "name.last"]
+				.iter()
+				.map(|field| doc! {*field: pattern.clone()})
+				.collect::<Vec<Document>>(),
+		);
+	}
+
+	if let Some(status) = &data.status {
+		filter.insert("status", to_bson(status).unwrap_or_default());
+	}
+
+	if let Some(_type) = &data._type {
+		filter.insert("type", to_bson(_type).unwrap_or_default());
+	}
+
+	filter
+}
+
+fn can_manage(actor: &User, account: &User) -> bool {
+	account._type == UserType::Member || has_permission(&actor._type, &Permission::ManageRoles)
+}
+
+async fn find_account(database: &Database, user: &AuthenticatedUser, username: &str) -> Result<(User, User), AppError> {
+	let actor: User = find_one_by_id(database, &user.log.user.id).await.ok_or(AppError::Forbidden("Access denied.".to_string()))?;
+	let account: User = find_one_by_username(database, username).await.ok_or(AppError::NotFound("User not found.".to_string()))?;
+
+	if !can_manage(&actor, &account) {
+		return Err(AppError::Forbidden("Access denied.".to_string()));
+	}
+
+	Ok((actor, account))
+}
+
+async fn save_user(database: &Database, previous: &User, account: &User, user: UserReference) -> Result<(), AppError> {
+	if let Err(err) = log_insert_one(database, previous, Some(user.clone())).await {
+		error!("{:?}", err);
+	}
+
+	match replace_one(database, account, Some(user)).await {
+		Ok(_) => Ok(()),
+		Err(err) => Err(database_error(err, "username")),
+	}
+}
+
+async fn authenticate_password(database: &Database, user: &mut User, password: &str, message: &str) -> Result<(), AppError> {
+	if user.password.locked > Utc::now() {
+		Metrics::instance().increment(&Metric::AuthenticationFailures, &[("reason", "account_locked")], 1.0);
+
+		return Err(AppError::Forbidden("Account is locked. Please try again later.".to_string()));
+	}
+
+	if !verify_password(password, &user.password.main) {
+		Metrics::instance().increment(&Metric::AuthenticationFailures, &[("reason", "invalid_password")], 1.0);
+
+		return Err(AppError::Unauthorized(record_failure(database, user, message).await));
+	}
+
+	if !is_hashed(&user.password.main) {
+		let main: String = hash_password(password);
+
+		if let Err(err) = update_password_main(database, &user.id, &user.password.main, &main).await {
+			error!("{:?}", err);
+		}
+
+		user.password.main = main;
+	}
+
+	Ok(())
+}
+
+async fn authenticate_totp(database: &Database, user: &mut User, code: &str) -> Result<(), AppError> {
+	let recovery: String = digest(code.trim().to_lowercase().as_bytes());
+
+	let modified: u64 = if let Some(step) = verify_code(&user.totp.secret, code, user.totp.last) {
+		user.totp.last = step;
+
+		update_totp_last(database, &user.id, step).await?.modified_count
+	} else if user.totp.recovery.contains(&recovery) {
+		warn!("User {} used a two-factor recovery code.", user.username);
+
+		user.totp.recovery.retain(|code| *code != recovery);
+
+		update_totp_recovery(database, &user.id, &recovery).await?.modified_count
+	} else {
+		0
+	};
+
+	if modified == 0 {
+		Metrics::instance().increment(&Metric::AuthenticationFailures, &[("reason", "invalid_totp")], 1.0);
+
+		return Err(AppError::Unauthorized(record_failure(database, user, "Invalid two-factor authentication code.").await));
+	}
+
+	Ok(())
+}
+
+async fn record_failure(database: &Database, user: &mut User, message: &str) -> String {
+	match update_password_attempt(database, &user.id).await {
+		Ok(Some(updated)) => user.password.attempt = updated.password.attempt,
+		Ok(None) => {}
+		Err(err) => error!("{:?}", err),
+	}
+
+	if user.password.attempt >= AppConfig::instance().user.password.attempt {
+		warn!("User {} is locked after {} failed attempts.", user.username, user.password.attempt);
+
+		user.password.attempt = 0;
+		user.password.locked = Utc::now() + TimeDelta::seconds(AppConfig::instance().user.password.lock);
+
+		if let Err(err) = update_password_locked(database, &user.id, user.password.locked).await {
+			error!("{:?}", err);
+		}
+	}
+
+	message.to_string()
+}
+
+async fn record_success(database: &Database, user: &mut User) {
+	if user.password.attempt > 0 {
+		user.password.attempt = 0;
+
+		if let Err(err) = reset_password_attempt(database, &user.id).await {
+			error!("{:?}", err);
+		}
+	}
+}
+
+pub async fn register(
+	_client: AuthenticatedClient,
+	database: web::Data<Database>,
+	data: web::Json<UserRegisterRequest>,
+) -> Result<HttpResponse, AppError> {
+	if let Some(message) = validate_register(&data) {
+		return Err(AppError::Validation(message));
+	}
+
+	let result: InsertOneResult = insert_one(
+		database.get_ref(),
+		&User {
+			contact: UserContact {
+				email: data.email.trim().to_lowercase(),
+				..Default::default()
+			},
+			name: data.name.clone(),
+			password: UserPassword {
+				main: hash_password(&data.password),
+				..Default::default()
+			},
+			status: Status::Active,
+			username: data.username.clone(),
+			..Default::default()
+		},
+		None,
+	)
+	.await
+	.map_err(|err| database_error(err, "username"))?;
+
+	Ok(HttpResponse::Ok().json(CreateResponse {
+		id: result.inserted_id.as_object_id().unwrap_or_default(),
+		response: "User has been registered successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn login(
+	_client: AuthenticatedClient,
+	request: HttpRequest,
+	database: web::Data<Database>,
+	data: web::Json<UserLoginRequest>,
+) -> Result<HttpResponse, AppError> {
+	let mut user: User = match find_one_by_username(database.get_ref(), &data.username).await {
+		Some(user) if user.status == Status::Active => user,
+		_ => return Err(AppError::Unauthorized("Invalid username or password.".to_string())),
+	};
+
+	authenticate_password(database.get_ref(), &mut user, &data.password, "Invalid username or password.").await?;
+
+	if user.totp.enabled {
+		match &data.code {
+			Some(code) => authenticate_totp(database.get_ref(), &mut user, code).await?,
+			None => {
+				return Ok(HttpResponse::Ok().json(UserLoginResponse {
+					response: "Two-factor authentication code required.".to_string(),
+					totp: true,
+					..Default::default()
+				}));
+			}
+		}
+	}
+
+	record_success(database.get_ref(), &mut user).await;
+
+	let user_reference: UserReference = UserReference {
+		id: user.id,
+		username: user.username.clone(),
+	};
+	let user_agent: &str = request.headers().get(USER_AGENT).and_then(|value| value.to_str().ok()).unwrap_or_default();
+	let (browser, device, os, platform): (UserLogBrowser, UserLogDevice, UserLogOs, Platform) = parse_user_agent(user_agent);
+	let mut log: UserLog = UserLog {
+		authentication: generate_authentication(),
+		browser,
+		device,
+		id: ObjectId::new(),
+		country: get_country_code(&request),
+		ip: get_client_ip(&request),
+		os,
+		platform: data.platform.clone().unwrap_or(platform),
+		remember: data.remember.unwrap_or_default(),
+		_type: Type::Login,
+		user: user_reference.clone(),
+		..Default::default()
+	};
+
+	let result: InsertOneResult = insert_one_log(database.get_ref(), &log, Some(user_reference)).await?;
+	log.id = result.inserted_id.as_object_id().unwrap_or(log.id);
+	log.created.timestamp = Utc::now();
+
+	let totp: bool = user.totp.enabled;
+	task::spawn(inspect_login(database.get_ref().clone(), user, log.clone()));
+
+	Ok(HttpResponse::Ok().json(UserLoginResponse {
+		expired: Some(get_session_expired(&log)),
+		authentication: log.authentication,
+		response: "Login successful.".to_string(),
+		result: true,
+		totp,
+	}))
+}
+
+pub async fn logout(
+	user: AuthenticatedUser,
+	request: HttpRequest,
+	database: web::Data<Database>,
+	_data: web::Json<UserLogoutRequest>,
+) -> Result<HttpResponse, AppError> {
+	revoke_session(database.get_ref(), &user.log, &request).await?;
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Logout successful.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn change_password(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserPasswordChangeRequest>,
+) -> Result<HttpResponse, AppError> {
+	let mut account: User = find_one_by_id(database.get_ref(), &user.log.user.id)
+		.await
+		.ok_or(AppError::NotFound("User not found.".to_string()))?;
+
+	authenticate_password(database.get_ref(), &mut account, &data.current, "Invalid current password.").await?;
+	record_success(database.get_ref(), &mut account).await;
+
+	if let Some(message) = validate_password(&data.password) {
+		return Err(AppError::Validation(message));
+	}
+
+	let main: String = hash_password(&data.password);
+
+	if update_password_main(database.get_ref(), &account.id, &account.password.main, &main).await?.modified_count == 0 {
+		return Err(AppError::Conflict("Password was changed by another request.".to_string()));
+	}
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Password has been changed successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn recover_password(
+	_client: AuthenticatedClient,
+	config: web::Data<AppConfig>,
+	database: web::Data<Database>,
+	data: web::Json<UserPasswordRecoverRequest>,
+) -> impl Responder {
+	let response: BaseResponse = BaseResponse {
+		response: "If the account exists, a recovery link has been sent to its email address.".to_string(),
+		result: true,
+	};
+
+	let mut user: User = match find_one_by_email(database.get_ref(), &data.email.trim().to_lowercase()).await {
+		Some(user) if user.status == Status::Active => user,
+		_ => return HttpResponse::Ok().json(response),
+	};
+
+	let recovery: String = generate_authentication();
+	user.password.recovery = digest(recovery.as_bytes());
+	user.password.recovery_expired = Utc::now() + TimeDelta::seconds(AppConfig::instance().user.password.recovery_ttl);
+
+	if let Err(err) = update_password_recovery(database.get_ref(), &user.id, &user.password.recovery, user.password.recovery_expired).await {
+		error!("{:?}", err);
+
+		return HttpResponse::Ok().json(response);
+	}
+
+	let config: AppConfig = config.get_ref().clone();
+	let body: String = format!(
+		"Hello {},\n\nUse the link below to reset your password. The link can only be used once and expires at {}.\n\n{}?username={}&recovery={}\n\nIf you did not request a password reset, you can ignore this email.",
+		user.username,
+		user.password.recovery_expired.to_rfc3339(),
+		config.user.password.recovery_url,
+		user.username,
+		recovery
+	);
+
+	task::spawn(async move {
+		send(
+			&config.email.user,
+			&user.contact.email,
+			&format!("[{}] Password Recovery", config.application.name),
+			&body,
+		)
+		.await;
+	});
+
+	HttpResponse::Ok().json(response)
+}
+
+pub async fn reset_password(
+	_client: AuthenticatedClient,
+	database: web::Data<Database>,
+	data: web::Json<UserPasswordResetRequest>,
+) -> Result<HttpResponse, AppError> {
+	let recovery: String = digest(data.recovery.as_bytes());
+	let mut user: User = match find_one_by_username(database.get_ref(), &data.username).await {
+		Some(user) if !data.recovery.is_empty() && is_equal(&user.password.recovery, &recovery) && user.password.recovery_expired > Utc::now() => user,
+		_ => return Err(AppError::Validation("Invalid or expired recovery link.".to_string())),
+	};
+
+	if let Some(message) = validate_password(&data.password) {
+		return Err(AppError::Validation(message));
+	}
+
+	user.password = UserPassword {
+		main: hash_password(&data.password),
+		..Default::default()
+	};
+	if update_password(database.get_ref(), &user.id, &recovery, &user.password).await?.matched_count == 0 {
+		return Err(AppError::Validation("Invalid or expired recovery link.".to_string()));
+	}
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Password has been reset successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn enroll_totp(
+	user: AuthenticatedUser,
+	config: web::Data<AppConfig>,
+	database: web::Data<Database>,
+) -> Result<HttpResponse, AppError> {
+	let account: User = match find_one_by_id(database.get_ref(), &user.log.user.id).await {
+		Some(account) if !account.totp.enabled => account,
+		Some(_) => return Err(AppError::Conflict("Two-factor authentication is already enabled.".to_string())),
+		None => return Err(AppError::NotFound("User not found.".to_string())),
+	};
+
+	let totp: UserTotp = UserTotp {
+		secret: generate_totp_secret(),
+		..Default::default()
+	};
+	update_totp(database.get_ref(), &account.id, &totp).await?;
+
+	Ok(HttpResponse::Ok().json(UserTotpEnrollResponse {
+		response: "Scan the QR code and verify a code to enable two-factor authentication.".to_string(),
+		result: true,
+		uri: get_uri(&config.application.name, &account.username, &totp.secret),
+		secret: totp.secret,
+	}))
+}
+
+pub async fn activate_totp(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserTotpRequest>,
+) -> Result<HttpResponse, AppError> {
+	let mut account: User = match find_one_by_id(database.get_ref(), &user.log.user.id).await {
+		Some(account) if !account.totp.enabled && !account.totp.secret.is_empty() => account,
+		_ => return Err(AppError::NotFound("Two-factor authentication enrolment not found.".to_string())),
+	};
+
+	let step: i64 = verify_code(&account.totp.secret, &data.code, account.totp.last)
+		.ok_or(AppError::Validation("Invalid two-factor authentication code.".to_string()))?;
+
+	let recovery: Vec<String> = (0..10).map(|_| hex::encode(rand::thread_rng().r#gen::<[u8; 5]>())).collect();
+
+	account.totp.enabled = true;
+	account.totp.last = step;
+	account.totp.recovery = recovery.iter().map(|code| digest(code.as_bytes())).collect();
+	update_totp(database.get_ref(), &account.id, &account.totp).await?;
+
+	Ok(HttpResponse::Ok().json(UserTotpVerifyResponse {
+		recovery,
+		response: "Two-factor authentication has been enabled successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn disable_totp(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserTotpRequest>,
+) -> Result<HttpResponse, AppError> {
+	let mut account: User = match find_one_by_id(database.get_ref(), &user.log.user.id).await {
+		Some(account) if account.totp.enabled => account,
+		_ => return Err(AppError::Conflict("Two-factor authentication is not enabled.".to_string())),
+	};
+
+	authenticate_totp(database.get_ref(), &mut account, &data.code).await?;
+	update_totp(database.get_ref(), &account.id, &UserTotp::default()).await?;
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Two-factor authentication has been disabled successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn reset_totp(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserTotpResetRequest>,
+) -> Result<HttpResponse, AppError> {
+	let (_, mut account): (User, User) = find_account(database.get_ref(), &user, &data.username).await?;
+
+	let previous: User = account.clone();
+	account.totp = UserTotp::default();
+	save_user(database.get_ref(), &previous, &account, user.log.user.clone()).await?;
+
+	warn!("Two-factor authentication for {} was reset by {}.", account.username, user.log.user.username);
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Two-factor authentication has been reset successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn change_role(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserRoleRequest>,
+) -> Result<HttpResponse, AppError> {
+	let mut account: User = find_one_by_username(database.get_ref(), &data.username)
+		.await
+		.ok_or(AppError::NotFound("User not found.".to_string()))?;
+
+	if account.id == user.log.user.id {
+		return Err(AppError::Forbidden("You cannot change your own role.".to_string()));
+	}
+
+	if account._type == data._type {
+		return Err(AppError::Conflict("User already has this role.".to_string()));
+	}
+
+	let previous: User = account.clone();
+	account._type = data._type.clone();
+	save_user(database.get_ref(), &previous, &account, user.log.user.clone()).await?;
+
+	info!("Role of {} changed from {:?} to {:?} by {}.", account.username, previous._type, account._type, user.log.user.username);
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "User role has been changed successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub fn get_skip(page: u64, size: i64) -> u64 {
+	page.saturating_sub(1).saturating_mul(size.max(0) as u64).min(i64::MAX as u64)
+}
+
+pub async fn list(
+	_user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserListRequest>,
+) -> Result<HttpResponse, AppError> {
+	let mut response: UserListResponse = UserListResponse {
+		page: data.page.unwrap_or(1).max(1),
+		size: data.size.unwrap_or(20).clamp(1, 100),
+		..Default::default()
+	};
+
+	let filter: Document = get_user_filter(&data);
+	response.total = count_documents(database.get_ref(), filter.clone()).await;
+
+	let users: Vec<User> = find(database.get_ref(), filter, get_skip(response.page, response.size), response.size)
+		.await
+		.ok_or(AppError::Internal("Failed to find users.".to_string()))?;
+
+	response.data = users.iter().map(UserData::from).collect();
+	response.response = "User list retrieved successfully.".to_string();
+	response.result = true;
+
+	Ok(HttpResponse::Ok().json(response))
+}
+
+pub async fn view(
+	_user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserUsernameRequest>,
+) -> Result<HttpResponse, AppError> {
+	let account: User = find_one_by_username(database.get_ref(), &data.username)
+		.await
+		.ok_or(AppError::NotFound("User not found.".to_string()))?;
+
+	Ok(HttpResponse::Ok().json(UserResponse {
+		data: Some(UserData::from(&account)),
+		response: "User retrieved successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn create(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserSaveRequest>,
+) -> Result<HttpResponse, AppError> {
+	let data: UserSaveRequest = data.into_inner();
+	let password: String = data.password.unwrap_or_default();
+
+	if let Some(message) = validate_username(&data.username)
+		.or_else(|| validate_password(&password))
+		.or_else(|| validate_email(&data.contact.email))
+	{
+		return Err(AppError::Validation(message));
+	}
+
+	let _type: UserType = data._type.unwrap_or_default();
+
+	if _type != UserType::Member
+		&& !find_one_by_id(database.get_ref(), &user.log.user.id).await.is_some_and(|actor| has_permission(&actor._type, &Permission::ManageRoles))
+	{
+		return Err(AppError::Forbidden("Access denied.".to_string()));
+	}
+
+	let result: InsertOneResult = insert_one(
+		database.get_ref(),
+		&User {
+			address: data.address,
+			contact: UserContact {
+				email: data.contact.email.trim().to_lowercase(),
+				..data.contact
+			},
+			gender: data.gender,
+			language: data.language,
+			name: data.name,
+			password: UserPassword {
+				main: hash_password(&password),
+				..Default::default()
+			},
+			status: Status::Active,
+			_type,
+			username: data.username,
+			..Default::default()
+		},
+		Some(user.log.user),
+	)
+	.await
+	.map_err(|err| database_error(err, "username"))?;
+
+	Ok(HttpResponse::Ok().json(CreateResponse {
+		id: result.inserted_id.as_object_id().unwrap_or_default(),
+		response: "User has been created successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn update(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserSaveRequest>,
+) -> Result<HttpResponse, AppError> {
+	let data: UserSaveRequest = data.into_inner();
+	let (_, mut account): (User, User) = find_account(database.get_ref(), &user, &data.username).await?;
+
+	if let Some(message) = validate_email(&data.contact.email)
+		.or_else(|| data.password.as_deref().and_then(validate_password))
+	{
+		return Err(AppError::Validation(message));
+	}
+
+	let previous: User = account.clone();
+	account.address = data.address;
+	account.contact = UserContact {
+		email: data.contact.email.trim().to_lowercase(),
+		..data.contact
+	};
+	account.gender = data.gender;
+	account.language = data.language;
+	account.name = data.name;
+
+	if let Some(password) = data.password {
+		account.password.main = hash_password(&password);
+	}
+
+	save_user(database.get_ref(), &previous, &account, user.log.user).await?;
+
+	Ok(HttpResponse::Ok().json(UserResponse {
+		data: Some(UserData::from(&account)),
+		response: "User has been updated successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn suspend(
+	user: AuthenticatedUser,
+	request: HttpRequest,
+	database: web::Data<Database>,
+	data: web::Json<UserUsernameRequest>,
+) -> Result<HttpResponse, AppError> {
+	let (actor, mut account): (User, User) = find_account(database.get_ref(), &user, &data.username).await?;
+
+	if actor.username == data.username {
+		return Err(AppError::Forbidden("You cannot suspend your own account.".to_string()));
+	}
+
+	let previous: User = account.clone();
+	account.status = Status::Inactive;
+	save_user(database.get_ref(), &previous, &account, user.log.user.clone()).await?;
+
+	for session in find_active_sessions(database.get_ref(), &account.id).await? {
+		revoke_session(database.get_ref(), &session, &request).await?;
+	}
+
+	warn!("User {} was suspended by {}.", account.username, user.log.user.username);
+
+	Ok(HttpResponse::Ok().json(UserResponse {
+		data: Some(UserData::from(&account)),
+		response: "User has been suspended successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn delete(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserUsernameRequest>,
+) -> Result<HttpResponse, AppError> {
+	let (actor, account): (User, User) = find_account(database.get_ref(), &user, &data.username).await?;
+
+	if actor.username == data.username {
+		return Err(AppError::Forbidden("You cannot delete your own account.".to_string()));
+	}
+
+	if let Err(err) = log_insert_one(database.get_ref(), &account, Some(user.log.user.clone())).await {
+		error!("{:?}", err);
+	}
+
+	let result: DeleteResult = delete_one_by_id(database.get_ref(), &account.id).await?;
+
+	if result.deleted_count == 0 {
+		return Err(AppError::NotFound("User not found.".to_string()));
+	}
+
+	warn!("User {} was deleted by {}.", account.username, user.log.user.username);
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "User has been deleted successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn profile(user: AuthenticatedUser, database: web::Data<Database>) -> Result<HttpResponse, AppError> {
+	let account: User = find_one_by_id(database.get_ref(), &user.log.user.id)
+		.await
+		.ok_or(AppError::NotFound("User not found.".to_string()))?;
+
+	Ok(HttpResponse::Ok().json(UserResponse {
+		data: Some(UserData::from(&account)),
+		response: "Profile retrieved successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn update_profile(
+	user: AuthenticatedUser,
+	database: web::Data<Database>,
+	data: web::Json<UserProfileRequest>,
+) -> Result<HttpResponse, AppError> {
+	if let Some(message) = validate_profile(&data) {
+		return Err(AppError::Validation(message));
+	}
+
+	let mut account: User = find_one_by_id(database.get_ref(), &user.log.user.id)
+		.await
+		.ok_or(AppError::NotFound("User not found.".to_string()))?;
+
+	let data: UserProfileRequest = data.into_inner();
+
+	if let Some(contact) = &data.contact
+		&& contact.email.trim().to_lowercase() != account.contact.email
+	{
+		let current: &str = data.current.as_deref().ok_or(AppError::Unauthorized("Current password is required to change email.".to_string()))?;
+
+		authenticate_password(database.get_ref(), &mut account, current, "Invalid current password.").await?;
+		record_success(database.get_ref(), &mut account).await;
+	}
+
+	let previous: User = account.clone();
+
+	if let Some(address) = data.address {
+		account.address = UserAddress {
+			city: address.city,
+			country: get_country(&address.country).unwrap_or_default(),
+			district: address.district,
+			state: address.state,
+			street: address.street,
+			zip: address.zip,
+		};
+	}
+
+	if let Some(contact) = data.contact {
+		account.contact = UserContact {
+			email: contact.email.trim().to_lowercase(),
+			phone: contact.phone.trim().to_string(),
+			..contact
+		};
+	}
+
+	if let Some(gender) = data.gender {
+		account.gender = gender;
+	}
+
+	if let Some(language) = data.language {
+		account.language = language;
+	}
+
+	if let Some(layout) = data.layout {
+		account.layout = layout;
+	}
+
+	if let Some(name) = data.name {
+		account.name = name;
+	}
+
+	save_user(database.get_ref(), &previous, &account, user.log.user).await?;
+
+	Ok(HttpResponse::Ok().json(UserResponse {
+		data: Some(UserData::from(&account)),
+		response: "Profile has been updated successfully.".to_string(),
+		result: true,
+	}))
+}
+
+async fn revoke_session(database: &Database, session: &UserLog, request: &HttpRequest) -> Result<(), AppError> {
+	let log: UserLog = UserLog {
+		id: ObjectId::new(),
+		ip: get_client_ip(request),
+		_type: Type::Logout,
+		..session.clone()
+	};
+
+	insert_one_log(database, &log, Some(session.user.clone())).await?;
+
+	Ok(())
+}
+
+async fn find_active_sessions(database: &Database, id: &ObjectId) -> Result<Vec<UserLog>, AppError> {
+	let since: DateTime<Utc> = Utc::now() - TimeDelta::seconds(AppConfig::instance().user.session.ttl.max(AppConfig::instance().user.session.remember_ttl));
+
+	find_by_user(database, id, since)
+		.await
+		.map(|logs| get_active_sessions(&logs))
+		.ok_or(AppError::Internal("Failed to find sessions.".to_string()))
+}
+
+pub async fn list_session(user: AuthenticatedUser, database: web::Data<Database>) -> Result<HttpResponse, AppError> {
+	let sessions: Vec<UserLog> = find_active_sessions(database.get_ref(), &user.log.user.id).await?;
+
+	Ok(HttpResponse::Ok().json(UserSessionListResponse {
+		data: sessions
+			.iter()
+			.map(|session| UserSessionData {
+				id: session.id,
+				browser: session.browser.clone(),
+				current: session.authentication == user.log.authentication,
+				device: session.device.clone(),
+				expired: get_session_expired(session),
+				ip: session.ip.clone(),
+				os: session.os.clone(),
+				platform: session.platform.clone(),
+				created: session.created.timestamp,
+			})
+			.collect(),
+		response: "Session list retrieved successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn revoke_session_by_id(
+	user: AuthenticatedUser,
+	request: HttpRequest,
+	database: web::Data<Database>,
+	data: web::Json<UserSessionRevokeRequest>,
+) -> Result<HttpResponse, AppError> {
+	let session: UserLog = find_active_sessions(database.get_ref(), &user.log.user.id)
+		.await?
+		.into_iter()
+		.find(|session| Some(session.id) == data.id)
+		.ok_or(AppError::NotFound("Session not found.".to_string()))?;
+
+	revoke_session(database.get_ref(), &session, &request).await?;
+
+	info!("Session {} of {} was revoked.", session.id, user.log.user.username);
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Session has been revoked successfully.".to_string(),
+		result: true,
+	}))
+}
+
+pub async fn revoke_session_other(
+	user: AuthenticatedUser,
+	request: HttpRequest,
+	database: web::Data<Database>,
+) -> Result<HttpResponse, AppError> {
+	let sessions: Vec<UserLog> = find_active_sessions(database.get_ref(), &user.log.user.id).await?;
+
+	for session in sessions.iter().filter(|session| session.authentication != user.log.authentication) {
+		revoke_session(database.get_ref(), session, &request).await?;
+	}
+
+	info!("Other sessions of {} were revoked.", user.log.user.username);
+
+	Ok(HttpResponse::Ok().json(BaseResponse {
+		response: "Other sessions have been revoked successfully.".to_string(),
+		result: true,
+	}))
+}
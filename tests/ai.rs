@@ -0,0 +1,292 @@
+mod common;
+
+use chrono::Utc;
+use actix_web::http::StatusCode;
+use common::ai::{start, MockAi, MockAiServer, KEY, LOCK};
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::integration::ai::models::{PredictRequest, PredictResponse, TrainRequest};
+use rizky_pratama_tan_api::integration::ai::services::{predict, train};
+use rizky_pratama_tan_api::integration::enums::{CircuitState, IntegrationError, Vendor};
+use rizky_pratama_tan_api::integration::models::Circuit;
+use rizky_pratama_tan_api::integration::services::CircuitBreaker;
+use rizky_pratama_tan_api::trading::data::enums::{Analysis, Timeframe};
+use std::time::{Duration, Instant};
+
+#[actix_web::test]
+async fn predict_sends_signed_request() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		prediction: Analysis::Bearish,
+		..Default::default()
+	}).await;
+
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest {
+		ticker: "XAUUSD".to_string(),
+		timeframe: Timeframe::OneMinute,
+		..Default::default()
+	}).await;
+
+	let response: PredictResponse = response.unwrap();
+	assert!(response.result);
+	assert_eq!(response.prediction, Analysis::Bearish);
+
+	let requests = server.requests();
+	assert_eq!(requests.len(), 1);
+	assert_eq!(requests[0].path, "/trading/data/predict");
+	assert_eq!(requests[0].token[0], "/trading/data/predict");
+	assert_eq!(requests[0].token.len(), 3);
+	assert_eq!(requests[0].body["ticker"], "XAUUSD");
+	assert_eq!(requests[0].body["timeframe"], "OneMinute");
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn train_sends_signed_request() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi::default()).await;
+
+	let response: Result<BaseResponse, IntegrationError> = train(&TrainRequest {
+		ticker: "XAUUSD".to_string(),
+		..Default::default()
+	}).await;
+
+	assert!(response.unwrap().result);
+
+	let requests = server.requests();
+	assert_eq!(requests.len(), 1);
+	assert_eq!(requests[0].token[0], "/trading/data/train");
+	assert_eq!(requests[0].body["ticker"], "XAUUSD");
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn predict_waits_for_slow_server() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		delay: Duration::from_millis(300),
+		..Default::default()
+	}).await;
+
+	let started: Instant = Instant::now();
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest::default()).await;
+
+	assert!(response.unwrap().result);
+	assert!(started.elapsed() >= Duration::from_millis(300));
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn predict_fails_on_server_error() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		status: StatusCode::INTERNAL_SERVER_ERROR,
+		..Default::default()
+	}).await;
+
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest::default()).await;
+
+	assert!(matches!(response, Err(IntegrationError::Status { status: 500, .. })));
+	assert_eq!(server.requests().len(), 3);
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn train_fails_on_unavailable_server() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		status: StatusCode::SERVICE_UNAVAILABLE,
+		..Default::default()
+	}).await;
+
+	let response: Result<BaseResponse, IntegrationError> = train(&TrainRequest::default()).await;
+
+	let err: IntegrationError = response.unwrap_err();
+	assert_eq!(err, IntegrationError::Status {
+		body: r#"{"response":"Internal server error.","result":false}"#.to_string(),
+		status: 503,
+	});
+	assert!(err.is_retryable());
+	assert_eq!(server.requests().len(), 1);
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn mock_rejects_invalid_token() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi::default()).await;
+
+	let response: reqwest::Response = reqwest::Client::new()
+		.post(format!("{}/trading/data/predict", server.base_url))
+		.header("pld-key", KEY)
+		.header("Content-Type", "application/json")
+		.body(serde_json::json!({"ticker": "XAUUSD", "timeframe": "OneMinute", "token": "invalid"}).to_string())
+		.send()
+		.await
+		.unwrap();
+
+	assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+	let body: BaseResponse = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+	assert_eq!(body.response, "Invalid token.");
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn mock_rejects_unknown_key() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi::default()).await;
+
+	let response: reqwest::Response = reqwest::Client::new()
+		.post(format!("{}/trading/data/train", server.base_url))
+		.header("pld-key", "unknown")
+		.header("Content-Type", "application/json")
+		.body(serde_json::json!({"ticker": "XAUUSD", "token": ""}).to_string())
+		.send()
+		.await
+		.unwrap();
+
+	assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+	assert!(server.requests().is_empty());
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn predict_retries_server_errors() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		failures: 2,
+		status: StatusCode::BAD_GATEWAY,
+		..Default::default()
+	}).await;
+
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest::default()).await;
+
+	assert!(response.unwrap().result);
+	assert_eq!(server.requests().len(), 3);
+
+	let mut nonces: Vec<String> = server.requests().into_iter().map(|request| request.token[2].clone()).collect();
+	nonces.dedup();
+	assert_eq!(nonces.len(), 3);
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn predict_does_not_retry_client_errors() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		status: StatusCode::FORBIDDEN,
+		..Default::default()
+	}).await;
+
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest::default()).await;
+
+	assert!(response.unwrap_err().is_misconfigured());
+	assert_eq!(server.requests().len(), 1);
+
+	let err: IntegrationError = IntegrationError::Status { body: String::new(), status: 502 };
+	CircuitBreaker::instance().record_failure(&Vendor::Ai, &err);
+	CircuitBreaker::instance().record_failure(&Vendor::Ai, &err);
+
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest::default()).await;
+
+	assert!(response.unwrap_err().is_misconfigured());
+	assert_eq!(CircuitBreaker::instance().get_circuit(&Vendor::Ai).failures, 2);
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn predict_honours_retry_after() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		failures: 1,
+		retry_after: Some(1),
+		status: StatusCode::TOO_MANY_REQUESTS,
+		..Default::default()
+	}).await;
+
+	let started: Instant = Instant::now();
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest::default()).await;
+
+	assert!(response.unwrap().result);
+	assert!(started.elapsed() >= Duration::from_secs(1));
+	assert_eq!(server.requests().len(), 2);
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn predict_surfaces_retry_after_beyond_backoff_max() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		failures: 1,
+		retry_after: Some(3600),
+		status: StatusCode::TOO_MANY_REQUESTS,
+		..Default::default()
+	}).await;
+
+	let started: Instant = Instant::now();
+	let response: Result<PredictResponse, IntegrationError> = predict(&PredictRequest::default()).await;
+
+	assert!(matches!(response, Err(IntegrationError::RateLimit { retry_after: Some(3600), .. })), "{:?}", response);
+	assert!(started.elapsed() < Duration::from_secs(10));
+	assert_eq!(server.requests().len(), 1);
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn half_open_circuit_admits_one_probe() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		delay: Duration::from_millis(300),
+		status: StatusCode::NOT_FOUND,
+		..Default::default()
+	}).await;
+
+	if let Some(circuit) = CircuitBreaker::instance().circuits.write().unwrap().get_mut(&Vendor::Ai) {
+		circuit.opened = Some(Utc::now() - chrono::Duration::hours(1));
+		circuit.state = CircuitState::Open;
+	}
+
+	let request: PredictRequest = PredictRequest::default();
+	let (first, second): (Result<PredictResponse, IntegrationError>, Result<PredictResponse, IntegrationError>) = tokio::join!(predict(&request), predict(&request));
+
+	assert!(matches!(first, Err(IntegrationError::Status { status: 404, .. })), "{:?}", first);
+	assert!(matches!(second, Err(IntegrationError::CircuitOpen { vendor: Vendor::Ai, .. })), "{:?}", second);
+	assert_eq!(server.requests().len(), 1);
+	assert_eq!(CircuitBreaker::instance().get_circuit(&Vendor::Ai).state, CircuitState::Closed);
+
+	server.stop().await;
+}
+
+#[actix_web::test]
+async fn circuit_opens_after_repeated_failures() {
+	let _lock = LOCK.lock().await;
+	let server: MockAiServer = start(MockAi {
+		status: StatusCode::INTERNAL_SERVER_ERROR,
+		..Default::default()
+	}).await;
+
+	for _ in 0..3 {
+		let _ = train(&TrainRequest::default()).await;
+	}
+
+	let circuit: Circuit = CircuitBreaker::instance().get_circuit(&Vendor::Ai);
+	assert_eq!(circuit.state, CircuitState::Open);
+	assert_eq!(circuit.failures, 3);
+
+	let response: Result<BaseResponse, IntegrationError> = train(&TrainRequest::default()).await;
+
+	assert!(matches!(response, Err(IntegrationError::CircuitOpen { vendor: Vendor::Ai, .. })));
+	assert_eq!(server.requests().len(), 3);
+
+	server.stop().await;
+}
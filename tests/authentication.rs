@@ -0,0 +1,429 @@
+use actix_web::http::header::HeaderValue;
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use rizky_pratama_tan_api::client::models::{Client, ClientRsa, ClientSecurity};
+use rizky_pratama_tan_api::client::services::ClientCache;
+use rizky_pratama_tan_api::core::authentication::enums::{Access, Scheme};
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::authentication::models::{AuthenticatedClient, AuthenticatedUser, AuthenticationNonce};
+use rizky_pratama_tan_api::core::authentication::services::{generate_nonce, get_nonce_expired, NonceStore};
+use rizky_pratama_tan_api::core::base::enums::Status;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::encryption::hmac::{digest, get_message, sign};
+use rizky_pratama_tan_api::core::encryption::rsa::{encrypt, generate_key};
+use serde_json::{json, Value};
+use std::sync::OnceLock;
+
+const SECRET: &str = "secret";
+
+fn initialize() -> &'static str {
+	static PUBLIC_KEY: OnceLock<String> = OnceLock::new();
+
+	PUBLIC_KEY.get_or_init(|| {
+		let (private, public): (String, String) = generate_key(&1024).unwrap();
+
+		let mut config: AppConfig = AppConfig::instance();
+		config.authentication.trusted_proxies = vec!["127.0.0.1".to_string()];
+		config.client.cache_ttl = 3600;
+		AppConfig::initialize(&config);
+
+		for (key, ip, access, scheme, status, timestamp) in [
+			("public", "*", Access::Public, Scheme::Rsa, Status::Active, true),
+			("private", "10.0.0.1", Access::Private, Scheme::Rsa, Status::Active, true),
+			("range", "10.1.0.0/16", Access::Private, Scheme::Rsa, Status::Active, true),
+			("relaxed", "*", Access::Public, Scheme::Rsa, Status::Active, false),
+			("signed", "*", Access::Public, Scheme::Hmac, Status::Active, true),
+			("suspended", "*", Access::Public, Scheme::Rsa, Status::Inactive, true),
+		] {
+			ClientCache::instance().set(&Client {
+				deny: vec!["10.1.0.5".to_string()],
+				ip: vec![ip.to_string()],
+				key: key.to_string(),
+				rsa: ClientRsa {
+					private: private.clone(),
+					public: public.clone(),
+				},
+				secret: SECRET.to_string(),
+				security: ClientSecurity {
+					access,
+					scheme,
+					timestamp,
+				},
+				status,
+				..Default::default()
+			});
+		}
+
+		public
+	})
+}
+
+async fn echo(client: AuthenticatedClient, data: web::Json<Value>) -> HttpResponse {
+	HttpResponse::Ok().json(json!({"key": client.client.key, "message": data["message"]}))
+}
+
+async fn session(user: AuthenticatedUser) -> HttpResponse {
+	HttpResponse::Ok().json(json!({"username": user.log.user.username}))
+}
+
+fn token(path: &str) -> String {
+	token_at(path, Utc::now())
+}
+
+fn token_at(path: &str, timestamp: DateTime<Utc>) -> String {
+	encrypt(&format!("{}~{}~{}", path, timestamp.to_rfc3339(), generate_nonce()), initialize())
+}
+
+fn signature(method: &str, path: &str, query: &str, timestamp: &str, nonce: &str, body: &str) -> String {
+	sign(&get_message(method, path, query, timestamp, nonce, &digest(body.as_bytes())), SECRET)
+}
+
+macro_rules! application {
+	($access:expr) => {
+		test::init_service(
+			App::new().service(
+				web::scope("/site")
+					.wrap(Authentication::new($access))
+					.route("/echo", web::post().to(echo))
+					.route("/session", web::post().to(session)),
+			),
+		)
+		.await
+	};
+}
+
+#[actix_web::test]
+async fn public_client_with_valid_token_is_authenticated() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let request = test::TestRequest::post()
+		.uri("/site/echo")
+		.insert_header(("pld-key", "public"))
+		.set_json(json!({"message": "Hello", "token": token("/site/echo")}))
+		.to_request();
+	let response: Value = test::call_and_read_body_json(&application, request).await;
+
+	assert_eq!(response, json!({"key": "public", "message": "Hello"}));
+}
+
+#[actix_web::test]
+async fn token_header_is_accepted() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let request = test::TestRequest::post()
+		.uri("/site/echo")
+		.insert_header(("pld-key", "public"))
+		.insert_header(("pld-token", token("/site/echo")))
+		.set_json(json!({"message": "Hello"}))
+		.to_request();
+	let response = test::call_service(&application, request).await;
+
+	assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn missing_key_is_unauthorized() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let request = test::TestRequest::post().uri("/site/echo").set_json(json!({"message": "Hello"})).to_request();
+	let response = test::call_service(&application, request).await;
+
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Unauthorized PLD key .");
+	assert!(!body.result);
+}
+
+#[actix_web::test]
+async fn malformed_key_is_unauthorized() {
+	initialize();
+	let application = application!(Access::Public);
+
+	for key in [HeaderValue::from_bytes(b"\xffpublic").unwrap(), HeaderValue::from_static("../public")] {
+		let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", key)).set_json(json!({"token": token("/site/echo")})).to_request();
+		let response = test::call_service(&application, request).await;
+
+		assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+	}
+}
+
+#[actix_web::test]
+async fn invalid_token_is_unauthorized() {
+	initialize();
+	let application = application!(Access::Public);
+
+	for (body, message) in [
+		(json!({"message": "Hello"}), "Invalid token."),
+		(json!({"token": "invalid"}), "Invalid token."),
+		(json!({"token": token("/site/other")}), "Invalid path."),
+	] {
+		let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "public")).set_json(body).to_request();
+		let response = test::call_service(&application, request).await;
+
+		assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+		let body: BaseResponse = test::read_body_json(response).await;
+		assert_eq!(body.response, message);
+	}
+}
+
+#[actix_web::test]
+async fn token_outside_window_is_expired() {
+	initialize();
+	let application = application!(Access::Public);
+
+	for timestamp in [Utc::now() - Duration::seconds(60), Utc::now() + Duration::seconds(60)] {
+		let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "public")).set_json(json!({"token": token_at("/site/echo", timestamp)})).to_request();
+		let response = test::call_service(&application, request).await;
+
+		assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+		let body: BaseResponse = test::read_body_json(response).await;
+		assert_eq!(body.response, "Token expired.");
+	}
+}
+
+#[actix_web::test]
+async fn token_without_nonce_is_invalid() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let token: String = encrypt(&format!("/site/echo~{}", Utc::now().to_rfc3339()), initialize());
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "public")).set_json(json!({"token": token})).to_request();
+	let response = test::call_service(&application, request).await;
+
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Invalid token.");
+}
+
+#[actix_web::test]
+async fn replayed_token_is_rejected() {
+	initialize();
+	let application = application!(Access::Public);
+	let token: String = token("/site/echo");
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "public")).set_json(json!({"token": token})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::OK);
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "public")).set_json(json!({"token": token})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Token has already been used.");
+}
+
+#[actix_web::test]
+async fn nonce_outlives_token_window() {
+	let mut client: Client = Client {
+		security: ClientSecurity {
+			timestamp: true,
+			..Default::default()
+		},
+		..Default::default()
+	};
+	assert!(get_nonce_expired(&client) <= Utc::now() + Duration::seconds(60));
+
+	client.security.timestamp = false;
+	assert!(get_nonce_expired(&client) > Utc::now() + Duration::seconds(86400));
+	assert!(get_nonce_expired(&client) <= Utc::now() + Duration::seconds(86430));
+}
+
+#[actix_web::test]
+async fn relaxed_client_rejects_tokens_older_than_nonce_ttl() {
+	initialize();
+	let application = application!(Access::Public);
+
+	for (timestamp, status) in [
+		(Utc::now() - Duration::hours(1), StatusCode::OK),
+		(Utc::now() - Duration::days(2), StatusCode::UNAUTHORIZED),
+		(Utc::now() + Duration::hours(1), StatusCode::UNAUTHORIZED),
+	] {
+		let request = test::TestRequest::post()
+			.uri("/site/echo")
+			.insert_header(("pld-key", "relaxed"))
+			.set_json(json!({"token": token_at("/site/echo", timestamp)}))
+			.to_request();
+		assert_eq!(test::call_service(&application, request).await.status(), status);
+	}
+}
+
+#[actix_web::test]
+async fn expired_nonces_are_purged() {
+	let nonce: AuthenticationNonce = AuthenticationNonce {
+		expired: Utc::now() - Duration::seconds(1),
+		key: "purge".to_string(),
+		nonce: generate_nonce(),
+	};
+
+	assert!(NonceStore::instance().insert(nonce.clone()));
+	assert!(NonceStore::instance().insert(nonce.clone()));
+
+	NonceStore::instance().purge();
+	assert!(!NonceStore::instance().nonces.read().unwrap().contains_key(&(nonce.key, nonce.nonce)));
+}
+
+#[actix_web::test]
+async fn suspended_client_is_unauthorized() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "suspended")).set_json(json!({"token": token("/site/echo")})).to_request();
+	let response = test::call_service(&application, request).await;
+
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Unauthorized PLD key suspended.");
+}
+
+#[actix_web::test]
+async fn public_client_is_denied_on_private_scope() {
+	initialize();
+	let application = application!(Access::Private);
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "public")).set_json(json!({"token": token("/site/echo")})).to_request();
+	let response = test::call_service(&application, request).await;
+
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Access denied.");
+}
+
+#[actix_web::test]
+async fn private_client_is_authenticated_by_ip() {
+	initialize();
+	let application = application!(Access::Private);
+
+	let request = test::TestRequest::post().uri("/site/echo").peer_addr("10.0.0.1:5000".parse().unwrap()).insert_header(("pld-key", "private")).set_json(json!({"message": "Hello"})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::OK);
+
+	let request = test::TestRequest::post().uri("/site/echo").peer_addr("10.0.0.2:5000".parse().unwrap()).insert_header(("pld-key", "private")).set_json(json!({"message": "Hello"})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Unauthorized IP address 10.0.0.2.");
+}
+
+#[actix_web::test]
+async fn missing_session_is_unauthorized() {
+	initialize();
+	let application = application!(Access::Private);
+
+	let request = test::TestRequest::post().uri("/site/session").peer_addr("10.0.0.1:5000".parse().unwrap()).insert_header(("pld-key", "private")).set_json(json!({"authentication": ""})).to_request();
+	let response = test::call_service(&application, request).await;
+
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Abnormal request detected.");
+}
+
+#[actix_web::test]
+async fn signed_request_is_authenticated() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let body: String = json!({"message": "Hello"}).to_string();
+	let timestamp: String = Utc::now().to_rfc3339();
+	let nonce: String = generate_nonce();
+
+	let request = test::TestRequest::post()
+		.uri("/site/echo?page=2")
+		.insert_header(("pld-key", "signed"))
+		.insert_header(("pld-timestamp", timestamp.as_str()))
+		.insert_header(("pld-nonce", nonce.as_str()))
+		.insert_header(("pld-signature", signature("POST", "/site/echo", "page=2", &timestamp, &nonce, &body)))
+		.insert_header(("content-type", "application/json"))
+		.set_payload(body.clone())
+		.to_request();
+	let response: Value = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(response, json!({"key": "signed", "message": "Hello"}));
+
+	let request = test::TestRequest::post()
+		.uri("/site/echo?page=2")
+		.insert_header(("pld-key", "signed"))
+		.insert_header(("pld-timestamp", timestamp.as_str()))
+		.insert_header(("pld-nonce", nonce.as_str()))
+		.insert_header(("pld-signature", signature("POST", "/site/echo", "page=2", &timestamp, &nonce, &body)))
+		.insert_header(("content-type", "application/json"))
+		.set_payload(body)
+		.to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Token has already been used.");
+}
+
+#[actix_web::test]
+async fn tampered_signed_request_is_rejected() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let timestamp: String = Utc::now().to_rfc3339();
+
+	for (uri, path, body, signed_body, headers) in [
+		("/site/echo", "/site/echo", json!({"message": "Bye"}), json!({"message": "Hello"}), true),
+		("/site/echo", "/site/session", json!({"message": "Hello"}), json!({"message": "Hello"}), true),
+		("/site/echo?page=2", "/site/echo", json!({"message": "Hello"}), json!({"message": "Hello"}), true),
+		("/site/echo", "/site/echo", json!({"message": "Hello"}), json!({"message": "Hello"}), false),
+	] {
+		let nonce: String = generate_nonce();
+		let mut request = test::TestRequest::post().uri(uri).insert_header(("pld-key", "signed")).insert_header(("content-type", "application/json")).set_payload(body.to_string());
+
+		if headers {
+			request = request
+				.insert_header(("pld-timestamp", timestamp.as_str()))
+				.insert_header(("pld-nonce", nonce.as_str()))
+				.insert_header(("pld-signature", signature("POST", path, "", &timestamp, &nonce, &signed_body.to_string())));
+		}
+
+		let response = test::call_service(&application, request.to_request()).await;
+
+		assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+		let body: BaseResponse = test::read_body_json(response).await;
+		assert_eq!(body.response, "Invalid signature.");
+	}
+}
+
+#[actix_web::test]
+async fn oversized_body_is_rejected() {
+	initialize();
+	let application = application!(Access::Public);
+
+	let body: String = json!({"message": "a".repeat(5 * 1024 * 1024), "token": token("/site/echo")}).to_string();
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "public")).insert_header(("content-type", "application/json")).set_payload(body).to_request();
+	let response = test::call_service(&application, request).await;
+
+	assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Request body is too large.");
+}
+
+#[actix_web::test]
+async fn private_client_is_authenticated_by_range_and_deny_list() {
+	initialize();
+	let application = application!(Access::Private);
+
+	for (peer, forwarded, status) in [
+		("10.1.2.3:5000", None, StatusCode::OK),
+		("10.1.0.5:5000", None, StatusCode::FORBIDDEN),
+		("10.2.0.1:5000", None, StatusCode::FORBIDDEN),
+		("127.0.0.1:5000", Some("10.1.2.3"), StatusCode::OK),
+		("127.0.0.1:5000", Some("10.1.0.5"), StatusCode::FORBIDDEN),
+		("10.2.0.1:5000", Some("10.1.2.3"), StatusCode::FORBIDDEN),
+	] {
+		let mut request = test::TestRequest::post().uri("/site/echo").peer_addr(peer.parse().unwrap()).insert_header(("pld-key", "range")).set_json(json!({"message": "Hello"}));
+
+		if let Some(forwarded) = forwarded {
+			request = request.insert_header(("x-forwarded-for", forwarded));
+		}
+
+		let response = test::call_service(&application, request.to_request()).await;
+
+		assert_eq!(response.status(), status, "{} {:?}", peer, forwarded);
+	}
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::authorization::enums::Permission;
+use rizky_pratama_tan_api::core::authorization::middleware::Authorization;
+use rizky_pratama_tan_api::core::authorization::services::{get_permissions, has_permission};
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::user::enums::UserType;
+use rizky_pratama_tan_api::user::models::{User, UserLoginResponse};
+use rizky_pratama_tan_api::user::repositories::{create_index, find_one_by_username, replace_one};
+use rizky_pratama_tan_api::user::routes;
+use serde_json::json;
+
+#[actix_web::test]
+async fn permissions_follow_user_type() {
+	assert_eq!(get_permissions(&UserType::Owner).len(), 7);
+	assert!(has_permission(&UserType::Owner, &Permission::ManageRoles));
+	assert!(has_permission(&UserType::Administrator, &Permission::ManageUsers));
+	assert!(has_permission(&UserType::Administrator, &Permission::ViewDiagnostics));
+	assert!(!has_permission(&UserType::Member, &Permission::ViewDiagnostics));
+	assert!(!has_permission(&UserType::Administrator, &Permission::ManageRoles));
+	assert!(!has_permission(&UserType::Administrator, &Permission::ManageClients));
+	assert!(has_permission(&UserType::Member, &Permission::ViewData));
+	assert!(!has_permission(&UserType::Member, &Permission::ManageAssets));
+}
+
+#[actix_web::test]
+async fn request_without_session_is_rejected() {
+	let application = test::init_service(
+		App::new().service(
+			web::resource("/asset")
+				.wrap(Authorization::new(Permission::ManageAssets))
+				.route(web::post().to(HttpResponse::Ok)),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/asset").to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn only_owner_can_change_role() {
+	common::initialize_client("authorization");
+
+	let database: Database = common::initialize_database("authorization").await;
+	create_index(&database).await;
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let mut authentication: Vec<String> = Vec::new();
+
+	for username in ["owner", "member"] {
+		let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "authorization")).set_json(json!({"email": format!("{}@example.com", username), "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": username})).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert!(response.result, "{}", response.response);
+
+		let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "authorization")).set_json(json!({"password": "password", "username": username})).to_request();
+		let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+		authentication.push(login.authentication);
+	}
+
+	let mut owner: User = find_one_by_username(&database, "owner").await.unwrap();
+	owner._type = UserType::Owner;
+	replace_one(&database, &owner, None).await.unwrap();
+
+	let request = test::TestRequest::post().uri("/user/role").insert_header(("pld-key", "authorization")).set_json(json!({"authentication": authentication[1], "type": "Owner", "username": "member"})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+	for (username, message) in [
+		("owner", "You cannot change your own role."),
+		("member", "User role has been changed successfully."),
+		("member", "User already has this role."),
+	] {
+		let request = test::TestRequest::post().uri("/user/role").insert_header(("pld-key", "authorization")).set_json(json!({"authentication": authentication[0], "type": "Administrator", "username": username})).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, message);
+	}
+
+	assert_eq!(find_one_by_username(&database, "member").await.unwrap()._type, UserType::Administrator);
+
+	database.drop().await.unwrap();
+}
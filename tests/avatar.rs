@@ -0,0 +1,118 @@
+mod common;
+
+use actix_web::{test, web, App};
+use image::{DynamicImage, ImageBuffer, ImageFormat, Rgb};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::error::enums::AppError;
+use rizky_pratama_tan_api::user::avatar::services::{get_avatar_sizes, process_avatar};
+use rizky_pratama_tan_api::user::models::{UserAvatarResponse, UserLoginResponse};
+use rizky_pratama_tan_api::user::repositories::{create_index, find_one_by_username};
+use rizky_pratama_tan_api::user::routes;
+use serde_json::json;
+use std::env;
+use std::io::Cursor;
+use std::path::Path;
+
+fn initialize_image(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+	let image: DynamicImage = DynamicImage::ImageRgb8(ImageBuffer::from_fn(width, height, |x, _| Rgb([(x % 256) as u8, 128, 64])));
+	let mut buffer: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+	image.write_to(&mut buffer, format).unwrap();
+
+	buffer.into_inner()
+}
+
+fn initialize_multipart(bytes: &[u8]) -> Vec<u8> {
+	let mut body: Vec<u8> = b"--boundary\r\nContent-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\nContent-Type: image/png\r\n\r\n".to_vec();
+	body.extend_from_slice(bytes);
+	body.extend_from_slice(b"\r\n--boundary--\r\n");
+
+	body
+}
+
+#[actix_web::test]
+async fn avatar_is_cropped_to_square_sizes() {
+	for format in [ImageFormat::Png, ImageFormat::Jpeg] {
+		let images: Vec<(u32, Vec<u8>)> = process_avatar(&initialize_image(300, 200, format), &[64, 128]).unwrap();
+
+		assert_eq!(images.len(), 2);
+
+		for (size, bytes) in images {
+			let image: DynamicImage = image::load_from_memory_with_format(&bytes, ImageFormat::Png).unwrap();
+			assert_eq!((image.width(), image.height()), (size, size));
+		}
+	}
+}
+
+#[actix_web::test]
+async fn avatar_rejects_invalid_files() {
+	assert_eq!(process_avatar(b"not an image", &[64]).unwrap_err(), AppError::Validation("Avatar must be a PNG, JPEG or WebP image.".to_string()));
+	assert_eq!(process_avatar(b"GIF89a\x01\x00\x01\x00", &[64]).unwrap_err(), AppError::Validation("Avatar must be a PNG, JPEG or WebP image.".to_string()));
+	assert_eq!(process_avatar(&initialize_image(64, 64, ImageFormat::Png)[..32], &[64]).unwrap_err(), AppError::Validation("Invalid image file.".to_string()));
+}
+
+#[actix_web::test]
+async fn avatar_sizes_are_configurable() {
+	let mut config: AppConfig = AppConfig::instance();
+	config.user.avatar.sizes = vec![256, 64, 8, 64];
+	AppConfig::initialize(&config);
+
+	assert_eq!(get_avatar_sizes(), vec![64, 256]);
+
+	AppConfig::initialize(&AppConfig::default());
+
+	assert_eq!(get_avatar_sizes(), vec![64, 128, 256]);
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn avatar_is_uploaded_and_removed() {
+	let directory: String = format!("{}/avatar_{}", env::temp_dir().to_string_lossy(), std::process::id());
+
+	let mut config: AppConfig = AppConfig::instance();
+	config.user.avatar.directory = directory.clone();
+	AppConfig::initialize(&config);
+
+	common::initialize_client("avatar");
+
+	let database: Database = common::initialize_database("avatar").await;
+	create_index(&database).await;
+
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "avatar")).set_json(json!({"email": "avatar@example.com", "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": "avatar"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "avatar")).set_json(json!({"password": "password", "username": "avatar"})).to_request();
+	let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+
+	let request = test::TestRequest::post()
+		.uri("/user/me/avatar")
+		.insert_header(("pld-key", "avatar"))
+		.insert_header(("pld-authentication", login.authentication.clone()))
+		.insert_header(("content-type", "multipart/form-data; boundary=boundary"))
+		.set_payload(initialize_multipart(&initialize_image(300, 200, ImageFormat::Png)))
+		.to_request();
+	let upload: UserAvatarResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(upload.result, "{}", upload.response);
+	assert_eq!(find_one_by_username(&database, "avatar").await.unwrap().avatar, upload.avatar);
+	assert!(Path::new(&directory).join(format!("{}_128.png", upload.avatar)).exists());
+
+	let request = test::TestRequest::post().uri("/user/me/avatar/delete").insert_header(("pld-key", "avatar")).set_json(json!({"authentication": login.authentication})).to_request();
+	let response: UserAvatarResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result);
+	assert!(!Path::new(&directory).join(format!("{}_128.png", upload.avatar)).exists());
+
+	database.drop().await.unwrap();
+}
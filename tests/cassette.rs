@@ -0,0 +1,311 @@
+mod common;
+
+use actix_web::{web, App, HttpResponse, HttpServer};
+use bson::doc;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::base::enums::Status;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::integration::enums::{IntegrationError, IntegrationMode, Vendor};
+use rizky_pratama_tan_api::integration::eodhd::models::{ExchangeSymbolRequest, ExchangeSymbolResponse, IntradayRequest, IntradayResponse, RealtimeRequest, RealtimeResponse};
+use rizky_pratama_tan_api::integration::eodhd::services::{exchange_symbol, intraday, realtime};
+use rizky_pratama_tan_api::integration::massive::enums::Timespan;
+use rizky_pratama_tan_api::integration::massive::models::{AggregateTickerRequest, AggregateTickerResponse};
+use rizky_pratama_tan_api::integration::massive::services::aggregate_ticker;
+use rizky_pratama_tan_api::integration::models::{Cassette, CassetteResponse};
+use rizky_pratama_tan_api::integration::services::{get_cassette_path, normalize_request, CircuitBreaker};
+use rizky_pratama_tan_api::trading::asset::models::{Asset, AssetSync};
+use rizky_pratama_tan_api::trading::asset::repositories::{find_one_by_id, insert_one as insert_one_asset};
+use rizky_pratama_tan_api::trading::data::enums::Timeframe;
+use rizky_pratama_tan_api::trading::data::models::Data;
+use rizky_pratama_tan_api::trading::data::repositories::{create_index, find};
+use rizky_pratama_tan_api::trading::data::services::{copy, get_data, get_realtime_at, update_last};
+use std::env;
+use std::fs::{create_dir_all, write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use strum::IntoEnumIterator;
+use tokio::sync::{Mutex, MutexGuard};
+
+static LOCK: Mutex<()> = Mutex::const_new(());
+
+async fn initialize() -> MutexGuard<'static, ()> {
+	let lock: MutexGuard<'static, ()> = LOCK.lock().await;
+
+	let mut config: AppConfig = AppConfig::default();
+	config.eodhd.base_url = "https://eodhd.com/api".to_string();
+	config.eodhd.key = "secret".to_string();
+	config.integration.cassette_directory = "tests/fixtures/cassettes".to_string();
+	config.integration.mode = IntegrationMode::Replay;
+	config.integration.retry = 0;
+	config.massive.base_url = "https://api.massive.com".to_string();
+	config.massive.key = "secret".to_string();
+	AppConfig::initialize(&config);
+
+	for vendor in Vendor::iter() {
+		CircuitBreaker::instance().reset(&vendor);
+	}
+
+	lock
+}
+
+async fn initialize_asset(database: &Database, last: DateTime<Utc>) -> Asset {
+	let _ = insert_one_asset(
+		database,
+		&Asset {
+			status: Status::Active,
+			sync: AssetSync {
+				error: None,
+				last,
+				symbol: "C:XAUUSD".to_string(),
+				synchronized: false,
+			},
+			ticker: "XAUUSD".to_string(),
+			..Default::default()
+		},
+		None,
+	).await;
+
+	database.collection::<Asset>("trading_asset").find_one(doc! {"ticker": "XAUUSD"}).await.unwrap().unwrap()
+}
+
+fn aggregate_request(from: &str, to: &str) -> AggregateTickerRequest {
+	AggregateTickerRequest {
+		from: from.to_string(),
+		multiplier: 1,
+		ticker: "C:XAUUSD".to_string(),
+		timespan: Timespan::Minute,
+		to: to.to_string(),
+		..Default::default()
+	}
+}
+
+#[actix_web::test]
+async fn replay_eodhd_exchange_symbol() {
+	let _lock = initialize().await;
+
+	let response: Vec<ExchangeSymbolResponse> = exchange_symbol(&ExchangeSymbolRequest {
+		code: "FOREX".to_string(),
+		..Default::default()
+	}).await.unwrap();
+
+	assert_eq!(response.len(), 2);
+	assert_eq!(response[0].code, "XAUUSD");
+	assert_eq!(response[1].code, "EURUSD");
+}
+
+#[actix_web::test]
+async fn replay_eodhd_intraday() {
+	let _lock = initialize().await;
+
+	let response: Vec<IntradayResponse> = intraday(&IntradayRequest {
+		from: Some(1735776000),
+		interval: Some("1m".to_string()),
+		symbol: "XAUUSD.FOREX".to_string(),
+		to: Some(1735776180),
+	}).await.unwrap();
+
+	assert_eq!(response.len(), 3);
+	assert_eq!(response[0].datetime, Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap());
+	assert_eq!(response[2].close, 2625.4);
+}
+
+#[actix_web::test]
+async fn replay_eodhd_realtime() {
+	let _lock = initialize().await;
+
+	let response: Vec<RealtimeResponse> = realtime(&RealtimeRequest {
+		s: "EURUSD.FOREX".to_string(),
+		symbol: "XAUUSD.FOREX".to_string(),
+	}).await.unwrap();
+
+	assert_eq!(response.len(), 1);
+	assert_eq!(response[0].code, "XAUUSD.FOREX");
+	assert_eq!(response[0].previous_close, 2624.1);
+}
+
+#[actix_web::test]
+async fn replay_massive_aggregate_ticker() {
+	let _lock = initialize().await;
+
+	let response: AggregateTickerResponse = aggregate_ticker(&aggregate_request("2025-01-02", "2025-01-05")).await.unwrap();
+
+	assert_eq!(response.results_count, 3);
+	assert_eq!(response.results[0].timestamp, Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap());
+	assert_eq!(response.results[1].open, 2624.9);
+}
+
+#[actix_web::test]
+async fn replay_maps_to_candles() {
+	let _lock = initialize().await;
+
+	let response: AggregateTickerResponse = aggregate_ticker(&aggregate_request("2025-01-02", "2025-01-05")).await.unwrap();
+	let data: Vec<Data> = get_data(&response, &Timeframe::OneMinute);
+
+	assert_eq!(data.len(), 3);
+	assert_eq!(data[0].datetime, Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap());
+	assert_eq!(data[0].timeframe, Timeframe::OneMinute);
+	assert!((data[0].change.amount - 1.4).abs() < 1e-9);
+	assert!((data[0].change.percentage - data[0].change.amount / data[0].price.open * 100.0).abs() < 1e-9);
+}
+
+#[actix_web::test]
+async fn replay_reports_missing_cassette() {
+	let _lock = initialize().await;
+
+	let response: Result<AggregateTickerResponse, IntegrationError> = aggregate_ticker(&aggregate_request("2000-01-01", "2000-01-04")).await;
+
+	assert!(matches!(response, Err(IntegrationError::Cassette(_))));
+}
+
+#[actix_web::test]
+async fn record_writes_cassette() {
+	let _lock = initialize().await;
+
+	let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base_url: String = format!("http://{}/api", listener.local_addr().unwrap());
+	let directory: PathBuf = env::temp_dir().join(format!("rizky-pratama-tan-api-cassette-{}", std::process::id()));
+	let server = HttpServer::new(|| {
+		App::new().route("/api/exchange-symbol-list/NYSE", web::get().to(|| async {
+			HttpResponse::Ok().content_type("application/json").body(r#"[{"Code":"IBM","Country":"USA","Currency":"USD","Exchange":"NYSE","Isin":null,"Name":"IBM","Type":"Common Stock"}]"#)
+		}))
+	}).workers(1).listen(listener).unwrap().run();
+	let handle = server.handle();
+	actix_web::rt::spawn(server);
+
+	let mut config: AppConfig = AppConfig::instance();
+	config.eodhd.base_url = base_url.clone();
+	config.integration.cassette_directory = directory.to_string_lossy().to_string();
+	config.integration.mode = IntegrationMode::Record;
+	AppConfig::initialize(&config);
+
+	let params: ExchangeSymbolRequest = ExchangeSymbolRequest {
+		code: "NYSE".to_string(),
+		..Default::default()
+	};
+	let recorded: Vec<ExchangeSymbolResponse> = exchange_symbol(&params).await.unwrap();
+
+	let path: PathBuf = get_cassette_path(&Vendor::Eodhd, &normalize_request(
+		"GET",
+		&format!("{}/exchange-symbol-list/NYSE?api_token=secret&fmt=json&code=NYSE&token=", base_url),
+		None,
+	));
+	let cassette: Cassette = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+	assert_eq!(cassette.request, "GET /api/exchange-symbol-list/NYSE?code=NYSE&fmt=json");
+	assert!(!cassette.request.contains("secret"));
+	assert_eq!(cassette.response.status, 200);
+
+	handle.stop(true).await;
+
+	config.integration.mode = IntegrationMode::Replay;
+	AppConfig::initialize(&config);
+
+	let replayed: Vec<ExchangeSymbolResponse> = exchange_symbol(&params).await.unwrap();
+	assert_eq!(replayed.len(), recorded.len());
+	assert_eq!(replayed[0].code, "IBM");
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn copy_inserts_replayed_candles() {
+	let _lock = initialize().await;
+	let database: Database = common::initialize_database("cassette").await;
+	create_index(&database, "XAUUSD").await;
+	let asset: Asset = initialize_asset(&database, Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()).await;
+
+	let response = copy(
+		&database,
+		&asset,
+		&Timeframe::OneMinute,
+		Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+		Some(Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap()),
+	).await.unwrap();
+
+	assert!(response.result);
+
+	let data: Vec<Data> = find(&database, "XAUUSD").await.unwrap();
+	assert_eq!(data.len(), 3);
+	assert!((data[0].change.amount - 1.4).abs() < 1e-9);
+
+	database.drop().await.unwrap();
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn get_realtime_updates_last() {
+	let _lock = initialize().await;
+	let database: Database = common::initialize_database("cassette").await;
+	create_index(&database, "XAUUSD").await;
+	let asset: Asset = initialize_asset(&database, Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()).await;
+
+	let directory: PathBuf = env::temp_dir().join(format!("rizky-pratama-tan-api-realtime-{}", std::process::id()));
+	let to: DateTime<Utc> = Utc.with_ymd_and_hms(2025, 1, 6, 3, 0, 0).unwrap();
+	let from: DateTime<Utc> = to - Duration::hours(6);
+	let request: String = normalize_request(
+		"GET",
+		&format!(
+			"https://api.massive.com/v2/aggs/ticker/C:XAUUSD/range/1/minute/{}/{}?from={}&multiplier=1&forexTicker=C:XAUUSD&timespan=minute&to={}",
+			from.format("%Y-%m-%d"),
+			to.format("%Y-%m-%d"),
+			from.format("%Y-%m-%d"),
+			to.format("%Y-%m-%d"),
+		),
+		None,
+	);
+
+	let mut config: AppConfig = AppConfig::instance();
+	config.integration.cassette_directory = directory.to_string_lossy().to_string();
+	AppConfig::initialize(&config);
+
+	let path: PathBuf = get_cassette_path(&Vendor::Massive, &request);
+	create_dir_all(path.parent().unwrap()).unwrap();
+	write(&path, serde_json::to_string(&Cassette {
+		request,
+		response: CassetteResponse {
+			body: r#"{"adjusted":true,"queryCount":0,"request_id":"realtime","results":[],"resultsCount":0,"status":"OK","ticker":"C:XAUUSD"}"#.to_string(),
+			retry_after: None,
+			status: 200,
+		},
+	}).unwrap()).unwrap();
+
+	let response = get_realtime_at(&database, &asset, &Timeframe::OneMinute, to).await.unwrap();
+	assert!(response.result);
+
+	let asset: Asset = find_one_by_id(&database, &asset.id).await.unwrap();
+	assert_eq!(asset.sync.last, to);
+	assert!(asset.sync.error.is_none());
+
+	database.drop().await.unwrap();
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn update_last_marks_asset_synchronized() {
+	let _lock = initialize().await;
+	let database: Database = common::initialize_database("cassette").await;
+	create_index(&database, "XAUUSD").await;
+	let asset: Asset = initialize_asset(&database, Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()).await;
+
+	let _ = copy(
+		&database,
+		&asset,
+		&Timeframe::OneMinute,
+		Some(Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+		Some(Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap()),
+	).await.unwrap();
+
+	let last: DateTime<Utc> = Utc.with_ymd_and_hms(2025, 1, 5, 0, 0, 0).unwrap();
+	update_last(&database, &asset, &last).await;
+
+	let updated: Asset = find_one_by_id(&database, &asset.id).await.unwrap();
+	assert_eq!(updated.sync.last, last);
+	assert!(!updated.sync.synchronized);
+
+	update_last(&database, &updated, &(Utc::now() + Duration::days(1))).await;
+
+	let updated: Asset = find_one_by_id(&database, &asset.id).await.unwrap();
+	assert!(updated.sync.synchronized);
+	assert!(updated.sync.last <= Utc::now());
+
+	database.drop().await.unwrap();
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use mongodb::Database;
+use rizky_pratama_tan_api::client::models::{Client, ClientData, ClientFile, ClientRsa, ClientSecurity};
+use rizky_pratama_tan_api::client::repositories::create_index;
+use rizky_pratama_tan_api::client::services::{create_client, delete_client, get_client, import_client, rotate_client, suspend_client, ClientCache};
+use rizky_pratama_tan_api::core::authentication::enums::{Access, Scheme};
+use rizky_pratama_tan_api::core::base::enums::Status;
+use rizky_pratama_tan_api::core::error::enums::AppError;
+use serde_json::{json, Value};
+use std::env;
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::path::PathBuf;
+
+#[test]
+fn client_data_does_not_expose_private_key() {
+	let client: Client = Client {
+		ip: vec!["*".to_string()],
+		key: "public".to_string(),
+		rsa: ClientRsa {
+			private: "private".to_string(),
+			public: "public".to_string(),
+		},
+		..Default::default()
+	};
+
+	let data: Value = serde_json::to_value(ClientData::from(&client)).unwrap();
+
+	assert_eq!(data["public_key"], json!("public"));
+	assert!(!data.to_string().contains("\"private\""));
+}
+
+#[tokio::test]
+async fn cached_client_is_returned_without_database() {
+	ClientCache::instance().set(&Client {
+		key: "cached".to_string(),
+		status: Status::Active,
+		..Default::default()
+	});
+
+	assert_eq!(get_client(None, "cached").await.unwrap().key, "cached");
+	assert!(get_client(None, "unknown").await.is_none());
+
+	ClientCache::instance().remove("cached");
+	assert!(get_client(None, "cached").await.is_none());
+}
+
+#[tokio::test]
+#[ignore = "requires MongoDB"]
+async fn client_lifecycle() {
+	let database: Database = common::initialize_database("client").await;
+	create_index(&database).await;
+	let data: ClientFile = ClientFile {
+		deny: Vec::new(),
+		ip: vec!["*".to_string()],
+		key: "lifecycle".to_string(),
+		security: ClientSecurity {
+			access: Access::Public,
+			scheme: Scheme::Hmac,
+			timestamp: true,
+		},
+	};
+
+	let client: Client = create_client(&database, &data, 1024, None).await.unwrap();
+	assert_eq!(client.status, Status::Active);
+	assert_eq!(create_client(&database, &data, 1024, None).await.unwrap_err(), AppError::Conflict("Key already exists.".to_string()));
+
+	let rotated: Client = rotate_client(&database, "lifecycle", 1024, None).await.unwrap();
+	assert_ne!(rotated.rsa.public, client.rsa.public);
+	assert_ne!(rotated.secret, client.secret);
+	assert_eq!(get_client(Some(&database), "lifecycle").await.unwrap().rsa.public, rotated.rsa.public);
+
+	let suspended: Client = suspend_client(&database, "lifecycle", None).await.unwrap();
+	assert_eq!(suspended.status, Status::Inactive);
+
+	delete_client(&database, "lifecycle").await.unwrap();
+	assert!(get_client(Some(&database), "lifecycle").await.is_none());
+
+	database.drop().await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires MongoDB"]
+async fn import_reads_keys_from_directory() {
+	let database: Database = common::initialize_database("client").await;
+	create_index(&database).await;
+	let directory: PathBuf = env::temp_dir().join(format!("rizky_pratama_tan_import_{}", std::process::id()));
+	let path: PathBuf = directory.join("imported");
+	create_dir_all(&path).unwrap();
+
+	let file: ClientFile = ClientFile {
+		ip: vec!["*".to_string()],
+		key: "imported".to_string(),
+		..Default::default()
+	};
+	write(path.join("client.pld"), serde_json::to_string(&file).unwrap()).unwrap();
+	write(path.join("private-key.pem"), "private").unwrap();
+	write(path.join("public-key.pem"), "public").unwrap();
+
+	let results: Vec<(String, Result<(), AppError>)> = import_client(&database, &directory.to_string_lossy()).await;
+	assert_eq!(results, vec![("imported".to_string(), Ok(()))]);
+
+	let client: Client = get_client(Some(&database), "imported").await.unwrap();
+	assert_eq!((client.rsa.private.as_str(), client.rsa.public.as_str()), ("private", "public"));
+
+	remove_dir_all(&directory).unwrap();
+	database.drop().await.unwrap();
+}
@@ -0,0 +1,213 @@
+use actix_web::dev::ServerHandle;
+use actix_web::http::StatusCode;
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use chrono::{DateTime, Utc};
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::encryption::rsa::{decrypt, generate_key, read_client_key};
+use rizky_pratama_tan_api::integration::ai::models::PredictResponse;
+use rizky_pratama_tan_api::integration::enums::Vendor;
+use rizky_pratama_tan_api::integration::services::CircuitBreaker;
+use rizky_pratama_tan_api::trading::data::enums::Analysis;
+use serde_json::Value;
+use std::env;
+use std::fs::{create_dir_all, write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::time::sleep;
+
+pub const KEY: &str = "mock-ai";
+
+pub static LOCK: AsyncMutex<()> = AsyncMutex::const_new(());
+
+#[derive(Clone, Debug)]
+pub struct MockAi {
+	pub delay: Duration,
+	pub failures: usize,
+	pub prediction: Analysis,
+	pub retry_after: Option<u64>,
+	pub status: StatusCode,
+}
+
+impl Default for MockAi {
+	fn default() -> Self {
+		MockAi {
+			delay: Duration::ZERO,
+			failures: usize::MAX,
+			prediction: Analysis::Bullish,
+			retry_after: None,
+			status: StatusCode::OK,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MockAiRequest {
+	pub body: Value,
+	pub path: String,
+	pub token: Vec<String>,
+}
+
+pub struct MockAiServer {
+	pub base_url: String,
+	pub requests: Arc<Mutex<Vec<MockAiRequest>>>,
+	handle: ServerHandle,
+}
+
+impl MockAiServer {
+	pub fn requests(&self) -> Vec<MockAiRequest> {
+		self.requests.lock().unwrap().clone()
+	}
+
+	pub async fn stop(self) {
+		self.handle.stop(true).await;
+	}
+}
+
+struct MockAiState {
+	mock: MockAi,
+	requests: Arc<Mutex<Vec<MockAiRequest>>>,
+}
+
+pub fn initialize_key() -> &'static PathBuf {
+	static DIRECTORY: OnceLock<PathBuf> = OnceLock::new();
+
+	DIRECTORY.get_or_init(|| {
+		let directory: PathBuf = env::temp_dir().join(format!("rizky-pratama-tan-api-{}", std::process::id())).join("clients");
+		let key_directory: PathBuf = directory.join(KEY);
+		create_dir_all(&key_directory).unwrap();
+
+		let (private_key, public_key): (String, String) = generate_key(&1024).unwrap();
+		write(key_directory.join("private-key.pem"), private_key).unwrap();
+		write(key_directory.join("public-key.pem"), public_key).unwrap();
+
+		directory
+	})
+}
+
+pub async fn start(mock: MockAi) -> MockAiServer {
+	let directory: &PathBuf = initialize_key();
+	CircuitBreaker::instance().reset(&Vendor::Ai);
+
+	let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base_url: String = format!("http://{}", listener.local_addr().unwrap());
+	let requests: Arc<Mutex<Vec<MockAiRequest>>> = Arc::new(Mutex::new(Vec::new()));
+	let state: web::Data<MockAiState> = web::Data::new(MockAiState {
+		mock,
+		requests: Arc::clone(&requests),
+	});
+
+	let server = HttpServer::new(move || {
+		App::new().app_data(state.clone()).route("/trading/data/predict", web::post().to(predict)).route("/trading/data/train", web::post().to(train))
+	}).workers(1).listen(listener).unwrap().run();
+	let handle: ServerHandle = server.handle();
+	actix_web::rt::spawn(server);
+
+	let mut config: AppConfig = AppConfig::instance();
+	config.ai.base_url = base_url.clone();
+	config.ai.key = KEY.to_string();
+	config.client.directory = directory.to_string_lossy().to_string();
+	config.integration.backoff = 10;
+	config.integration.backoff_max = 2000;
+	config.integration.circuit_cooldown = 60;
+	config.integration.circuit_threshold = 3;
+	config.integration.retry = 2;
+	AppConfig::initialize(&config);
+
+	MockAiServer {
+		base_url,
+		requests,
+		handle,
+	}
+}
+
+async fn authenticate(request: &HttpRequest, body: &web::Bytes, state: &MockAiState) -> Result<(), HttpResponse> {
+	let key: &str = request.headers().get("pld-key").and_then(|value| value.to_str().ok()).unwrap_or_default();
+
+	if key != KEY {
+		return Err(HttpResponse::Unauthorized().json(BaseResponse {
+			response: format!("Unauthorized PLD key {}.", key),
+			result: false,
+		}));
+	}
+
+	let body: Value = serde_json::from_slice(body).unwrap_or_default();
+	let token: Vec<String> = decrypt(body.get("token").and_then(Value::as_str).unwrap_or_default(), &read_client_key(KEY, "private-key.pem")).split("~").map(String::from).collect();
+
+	let replayed: bool = state.requests.lock().unwrap().iter().any(|request| request.token.get(2) == token.get(2));
+
+	state.requests.lock().unwrap().push(MockAiRequest {
+		body,
+		path: request.path().to_string(),
+		token: token.clone(),
+	});
+
+	if replayed {
+		return Err(HttpResponse::Unauthorized().json(BaseResponse {
+			response: "Token has already been used.".to_string(),
+			result: false,
+		}));
+	}
+
+	if token.len() != 3 || token[0] != request.path() {
+		return Err(HttpResponse::Unauthorized().json(BaseResponse {
+			response: "Invalid token.".to_string(),
+			result: false,
+		}));
+	}
+
+	match DateTime::parse_from_rfc3339(&token[1]) {
+		Ok(timestamp) if (Utc::now() - timestamp.to_utc()).num_seconds().abs() <= 30 => {}
+		_ => {
+			return Err(HttpResponse::Unauthorized().json(BaseResponse {
+				response: "Token expired.".to_string(),
+				result: false,
+			}));
+		}
+	}
+
+	sleep(state.mock.delay).await;
+
+	let failures: usize = state.requests.lock().unwrap().len();
+
+	if state.mock.status != StatusCode::OK && failures <= state.mock.failures {
+		let mut response = HttpResponse::build(state.mock.status);
+
+		if let Some(retry_after) = state.mock.retry_after {
+			response.insert_header(("Retry-After", retry_after.to_string()));
+		}
+
+		return Err(response.json(BaseResponse {
+			response: "Internal server error.".to_string(),
+			result: false,
+		}));
+	}
+
+	Ok(())
+}
+
+async fn predict(request: HttpRequest, body: web::Bytes, state: web::Data<MockAiState>) -> HttpResponse {
+	if let Err(response) = authenticate(&request, &body, &state).await {
+		return response;
+	}
+
+	HttpResponse::Ok().json(PredictResponse {
+		prediction: state.mock.prediction.clone(),
+		response: "Prediction generated successfully.".to_string(),
+		result: true,
+	})
+}
+
+async fn train(request: HttpRequest, body: web::Bytes, state: web::Data<MockAiState>) -> HttpResponse {
+	if let Err(response) = authenticate(&request, &body, &state).await {
+		return response;
+	}
+
+	HttpResponse::Ok().json(BaseResponse {
+		response: "Model trained successfully.".to_string(),
+		result: true,
+	})
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+pub mod ai;
+
+use mongodb::{Client as MongoClient, Database};
+use rizky_pratama_tan_api::client::models::{Client, ClientSecurity};
+use rizky_pratama_tan_api::client::services::ClientCache;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::base::enums::Status;
+use std::env;
+
+pub fn initialize_client(key: &str) {
+	ClientCache::instance().set(&Client {
+		ip: vec!["*".to_string()],
+		key: key.to_string(),
+		security: ClientSecurity {
+			access: Access::Private,
+			..Default::default()
+		},
+		status: Status::Active,
+		..Default::default()
+	});
+}
+
+pub async fn initialize_database(name: &str) -> Database {
+	let client: MongoClient = MongoClient::with_uri_str(env::var("DATABASE_CONNECTION_STRING").unwrap_or("mongodb://127.0.0.1:27017".to_string())).await.unwrap();
+
+	client.database(&format!("rizky_pratama_tan_test_{}_{}", name, std::process::id()))
+}
@@ -0,0 +1,173 @@
+use rizky_pratama_tan_api::core::config::enums::{Backend, ConfigError};
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::integration::enums::{IntegrationMode, Vendor};
+use std::collections::HashMap;
+
+fn values(entries: &[(&str, &str)]) -> HashMap<String, String> {
+	entries.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+}
+
+fn valid() -> HashMap<String, String> {
+	values(&[
+		("APPLICATION_NAME", "Rizky Pratama Tan"),
+		("DATABASE_CONNECTION_STRING", "mongodb://127.0.0.1:27017"),
+		("DATABASE_NAME", "rizky_pratama_tan"),
+		("SERVER_ADDRESS", "0.0.0.0:5000"),
+	])
+}
+
+#[test]
+fn every_missing_key_is_reported() {
+	let errors: Vec<ConfigError> = AppConfig::from_values(HashMap::new()).unwrap_err();
+
+	for key in ["APPLICATION_NAME", "DATABASE_CONNECTION_STRING", "DATABASE_NAME", "SERVER_ADDRESS"] {
+		assert!(errors.contains(&ConfigError::Missing(key.to_string())), "{} is not reported", key);
+	}
+
+	assert_eq!(errors.len(), 4);
+}
+
+#[test]
+fn invalid_values_are_reported() {
+	let mut values: HashMap<String, String> = valid();
+	values.insert("API_DISCORD_CHANNEL_ID".to_string(), "channel".to_string());
+	values.insert("API_EODHD_BASE_URL".to_string(), "eodhd.com/api".to_string());
+	values.insert("API_EODHD_TOKEN".to_string(), "token".to_string());
+	values.insert("DATABASE_CONNECTION_STRING".to_string(), "127.0.0.1:27017".to_string());
+	values.insert("SERVER_ADDRESS".to_string(), "localhost".to_string());
+
+	let keys: Vec<String> = AppConfig::from_values(values)
+		.unwrap_err()
+		.into_iter()
+		.map(|err| match err {
+			ConfigError::Invalid { key, .. } => key,
+			ConfigError::Missing(key) => panic!("{} is reported as missing", key),
+		})
+		.collect();
+
+	assert_eq!(keys, vec!["API_DISCORD_CHANNEL_ID", "API_EODHD_BASE_URL", "DATABASE_CONNECTION_STRING", "SERVER_ADDRESS"]);
+}
+
+#[test]
+fn vendor_key_is_required_with_base_url() {
+	let mut values: HashMap<String, String> = valid();
+	values.insert("API_MASSIVE_BASE_URL".to_string(), "https://api.massive.com".to_string());
+
+	assert_eq!(AppConfig::from_values(values).unwrap_err(), vec![ConfigError::Missing("API_MASSIVE_KEY".to_string())]);
+}
+
+#[test]
+fn smtp_requires_port_and_sender() {
+	let mut values: HashMap<String, String> = valid();
+	values.insert("EMAIL_SMTP_SERVER".to_string(), "smtp.example.com".to_string());
+	values.insert("EMAIL_USER".to_string(), "sender".to_string());
+
+	let errors: Vec<ConfigError> = AppConfig::from_values(values).unwrap_err();
+
+	assert!(errors.contains(&ConfigError::Missing("EMAIL_SMTP_PORT".to_string())));
+	assert!(errors.iter().any(|err| err.to_string().starts_with("EMAIL_USER is invalid")));
+}
+
+#[test]
+fn smtp_requires_recovery_url() {
+	let mut values: HashMap<String, String> = valid();
+	values.insert("EMAIL_SMTP_PORT".to_string(), "465".to_string());
+	values.insert("EMAIL_SMTP_SERVER".to_string(), "smtp.example.com".to_string());
+	values.insert("EMAIL_USER".to_string(), "sender@example.com".to_string());
+
+	assert_eq!(AppConfig::from_values(values.clone()).unwrap_err(), vec![ConfigError::Missing("USER_PASSWORD_RECOVERY_URL".to_string())]);
+
+	values.insert("USER_PASSWORD_RECOVERY_URL".to_string(), "https://example.com/reset".to_string());
+	assert!(AppConfig::from_values(values).is_ok());
+}
+
+#[test]
+fn valid_values_are_loaded() {
+	let mut values: HashMap<String, String> = valid();
+	values.insert("API_AI_BASE_URL".to_string(), "https://api.openai.com/v1/".to_string());
+	values.insert("API_AI_KEY".to_string(), " key ".to_string());
+	values.insert("API_DISCORD_CHANNEL_ID".to_string(), "1234567890".to_string());
+	values.insert("EMAIL_SMTP_PORT".to_string(), "465".to_string());
+
+	let config: AppConfig = AppConfig::from_values(values).unwrap();
+
+	assert_eq!(config.ai.base_url, "https://api.openai.com/v1");
+	assert_eq!(config.ai.key, "key");
+	assert_eq!(config.application.name, "Rizky Pratama Tan");
+	assert_eq!(config.discord.channel_id, 1234567890);
+	assert_eq!(config.email.smtp_port, 465);
+	assert!(config.eodhd.base_url.is_empty());
+}
+
+#[test]
+fn tunables_are_validated() {
+	let mut values: HashMap<String, String> = valid();
+	values.insert("API_INTEGRATION_MODE".to_string(), "rewind".to_string());
+	values.insert("AUTHENTICATION_NONCE_STORE".to_string(), "redis".to_string());
+	values.insert("AUTHENTICATION_TRUSTED_PROXIES".to_string(), "10.0.0.0/8, proxy".to_string());
+	values.insert("HEALTH_SYNC_MAX_AGE".to_string(), "soon".to_string());
+	values.insert("LOG_INTEGRATION_BODY_LIMIT".to_string(), "-1".to_string());
+	values.insert("LOG_INTEGRATION_LEVEL_EODHD".to_string(), "info, rizky_pratama_tan_api=trace".to_string());
+	values.insert("RATE_LIMIT_SITE_CAPACITY".to_string(), "ten".to_string());
+	values.insert("USER_AVATAR_SIZES".to_string(), "64,large".to_string());
+	values.insert("USER_PASSWORD_ATTEMPT".to_string(), "five".to_string());
+
+	let keys: Vec<String> = AppConfig::from_values(values)
+		.unwrap_err()
+		.into_iter()
+		.map(|err| match err {
+			ConfigError::Invalid { key, .. } => key,
+			ConfigError::Missing(key) => panic!("{} is reported as missing", key),
+		})
+		.collect();
+
+	assert_eq!(
+		keys,
+		vec![
+			"AUTHENTICATION_NONCE_STORE",
+			"AUTHENTICATION_TRUSTED_PROXIES",
+			"HEALTH_SYNC_MAX_AGE",
+			"API_INTEGRATION_MODE",
+			"LOG_INTEGRATION_BODY_LIMIT",
+			"LOG_INTEGRATION_LEVEL_EODHD",
+			"RATE_LIMIT_SITE_CAPACITY",
+			"USER_AVATAR_SIZES",
+			"USER_PASSWORD_ATTEMPT"
+		]
+	);
+}
+
+#[test]
+fn tunables_have_defaults() {
+	let mut values: HashMap<String, String> = valid();
+	values.insert("AUTHENTICATION_TRUSTED_PROXIES".to_string(), "127.0.0.1, 10.0.0.0/8".to_string());
+	values.insert("LOG_INTEGRATION_LEVEL_EODHD".to_string(), "DEBUG".to_string());
+	values.insert("RATE_LIMIT_BACKEND".to_string(), "MongoDB".to_string());
+	values.insert("RATE_LIMIT_SITE_CAPACITY".to_string(), "10".to_string());
+	values.insert("RATE_LIMIT_SITE_IP_REFILL".to_string(), "120".to_string());
+
+	let config: AppConfig = AppConfig::from_values(values).unwrap();
+
+	assert_eq!(config.authentication.nonce_store, Backend::Memory);
+	assert_eq!(config.authentication.nonce_ttl, 86400);
+	assert_eq!(config.authentication.payload_limit, 4194304);
+	assert_eq!(config.authentication.trusted_proxies, vec!["127.0.0.1", "10.0.0.0/8"]);
+	assert_eq!(config.client.directory, "clients");
+	assert_eq!(config.integration.mode, IntegrationMode::Live);
+	assert_eq!(config.health.error_history, 50);
+	assert_eq!(config.health.scheduler_max_age, 300);
+	assert_eq!(config.health.sync_max_age, 900);
+	assert_eq!(config.integration.retry, 3);
+	assert_eq!(config.log.integration.body_limit, 1024);
+	assert_eq!(config.log.integration.level[&Vendor::Ai], "info");
+	assert_eq!(config.log.integration.level[&Vendor::Eodhd], "debug");
+	assert_eq!(config.log.integration.secret_headers, vec!["authorization", "pld-key"]);
+	assert!(config.metrics.token.is_empty());
+	assert_eq!(config.rate_limit.backend, Backend::Mongodb);
+	assert_eq!((config.rate_limit.scopes["site"].capacity, config.rate_limit.scopes["site"].refill), (10.0, 10.0 / 60.0));
+	assert_eq!((config.rate_limit.scopes["site_ip"].capacity, config.rate_limit.scopes["site_ip"].refill), (0.0, 2.0));
+	assert_eq!(config.rate_limit.scopes.len(), 2);
+	assert_eq!(config.user.avatar.sizes, vec![64, 128, 256]);
+	assert_eq!(config.user.password.attempt, 5);
+	assert_eq!(config.user.session.ttl, 86400);
+}
@@ -0,0 +1,18 @@
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::email::services::send;
+
+#[actix_web::test]
+async fn send_skips_unconfigured_smtp_and_invalid_addresses() {
+	AppConfig::initialize(&AppConfig::default());
+
+	assert!(!send("sender@example.com", "user@example.com", "Subject", "Body").await);
+
+	let mut config: AppConfig = AppConfig::default();
+	config.email.smtp_port = 465;
+	config.email.smtp_server = "smtp.example.com".to_string();
+	config.email.user = "sender@example.com".to_string();
+	AppConfig::initialize(&config);
+
+	assert!(!send("sender", "user@example.com", "Subject", "Body").await);
+	assert!(!send("sender@example.com", "not an address", "Subject", "Body").await);
+}
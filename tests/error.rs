@@ -0,0 +1,86 @@
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse, ResponseError};
+use rizky_pratama_tan_api::core::authentication::enums::AuthenticationError;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::error::enums::AppError;
+use rizky_pratama_tan_api::core::error::services::json_error_handler;
+use rizky_pratama_tan_api::integration::enums::IntegrationError;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Payload {
+	#[allow(dead_code)]
+	name: String,
+}
+
+async fn echo(_data: web::Json<Payload>) -> HttpResponse {
+	HttpResponse::Ok().finish()
+}
+
+async fn missing() -> Result<HttpResponse, AppError> {
+	Err(AppError::NotFound("User not found.".to_string()))
+}
+
+#[actix_web::test]
+async fn status_code_matches_variant() {
+	for (error, status) in [
+		(AppError::Conflict(String::new()), StatusCode::CONFLICT),
+		(AppError::Forbidden(String::new()), StatusCode::FORBIDDEN),
+		(AppError::Internal(String::new()), StatusCode::INTERNAL_SERVER_ERROR),
+		(AppError::NotFound(String::new()), StatusCode::NOT_FOUND),
+		(AppError::Unauthorized(String::new()), StatusCode::UNAUTHORIZED),
+		(AppError::Upstream(String::new()), StatusCode::BAD_GATEWAY),
+		(AppError::Validation(String::new()), StatusCode::BAD_REQUEST),
+	] {
+		assert_eq!(error.status_code(), status, "{:?}", error);
+	}
+}
+
+#[actix_web::test]
+async fn internal_detail_is_not_exposed() {
+	assert_eq!(AppError::Internal("connection refused".to_string()).to_string(), "Internal server error.");
+	assert_eq!(AppError::Conflict("Username already exists.".to_string()).to_string(), "Username already exists.");
+}
+
+#[actix_web::test]
+async fn authentication_error_keeps_status() {
+	assert_eq!(AppError::from(AuthenticationError::AccessDenied), AppError::Forbidden("Access denied.".to_string()));
+	assert_eq!(AppError::from(AuthenticationError::TokenExpired), AppError::Unauthorized("Token expired.".to_string()));
+}
+
+#[actix_web::test]
+async fn integration_error_is_upstream() {
+	let error: AppError = AppError::from(IntegrationError::Status {
+		body: "secret".to_string(),
+		status: 500,
+	});
+
+	assert_eq!(error.status_code(), StatusCode::BAD_GATEWAY);
+	assert!(!error.to_string().contains("secret"));
+}
+
+#[actix_web::test]
+async fn error_is_returned_in_envelope() {
+	let application = test::init_service(
+		App::new()
+			.app_data(web::JsonConfig::default().error_handler(json_error_handler))
+			.route("/echo", web::post().to(echo))
+			.route("/missing", web::post().to(missing)),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/missing").to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!((body.result, body.response.as_str()), (false, "User not found."));
+
+	let request = test::TestRequest::post().uri("/echo").insert_header(("content-type", "application/json")).set_payload("{\"name\":").to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert!(!body.result);
+	assert!(body.response.starts_with("Invalid request body."), "{}", body.response);
+}
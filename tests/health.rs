@@ -0,0 +1,128 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use chrono::{DateTime, Duration, Utc};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::base::enums::Status;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::health::models::{HealthJob, HealthReadyResponse};
+use rizky_pratama_tan_api::core::health::services::{get_discord_check, get_scheduler_check, get_sync_check, health, ready, HealthMonitor};
+use rizky_pratama_tan_api::trading::asset::models::{Asset, AssetSync};
+use std::collections::HashMap;
+
+fn asset(ticker: &str, status: Status, synchronized: bool) -> Asset {
+	Asset {
+		status,
+		sync: AssetSync {
+			last: Utc::now() - Duration::days(30),
+			synchronized,
+			..Default::default()
+		},
+		ticker: ticker.to_string(),
+		..Default::default()
+	}
+}
+
+#[actix_web::test]
+async fn discord_check_requires_connection_when_configured() {
+	assert!(get_discord_check(false, false).healthy);
+	assert!(get_discord_check(true, true).healthy);
+	assert!(!get_discord_check(true, false).healthy);
+}
+
+#[actix_web::test]
+async fn scheduler_check_detects_stale_jobs() {
+	assert!(!get_scheduler_check(&[], 300).healthy);
+
+	let job: HealthJob = HealthJob {
+		last: Some(Utc::now() - Duration::seconds(30)),
+		name: "authentication_nonce_purge".to_string(),
+		running: true,
+		started: Utc::now() - Duration::hours(1),
+		..Default::default()
+	};
+	assert!(get_scheduler_check(std::slice::from_ref(&job), 300).healthy);
+
+	let stale: HealthJob = HealthJob {
+		last: Some(Utc::now() - Duration::seconds(600)),
+		name: "trading_data_sync_AAPL".to_string(),
+		..job.clone()
+	};
+	let check = get_scheduler_check(&[job, stale], 300);
+	assert!(!check.healthy);
+	assert!(check.detail.contains("trading_data_sync_AAPL"), "{}", check.detail);
+}
+
+#[actix_web::test]
+async fn sync_check_uses_latest_vendor_call() {
+	let started: DateTime<Utc> = Utc::now() - Duration::hours(1);
+	let syncs: HashMap<String, DateTime<Utc>> = HashMap::from([
+		("OLD".to_string(), Utc::now() - Duration::seconds(1200)),
+		("NEW".to_string(), Utc::now() - Duration::seconds(60)),
+	]);
+
+	assert!(get_sync_check(&[], &syncs, started, 900).healthy);
+	assert!(get_sync_check(&[asset("OLD", Status::Active, true), asset("NEW", Status::Active, true)], &syncs, started, 900).healthy);
+	assert!(!get_sync_check(&[asset("OLD", Status::Active, true), asset("NEW", Status::Inactive, true)], &syncs, started, 900).healthy);
+	assert!(!get_sync_check(&[asset("OLD", Status::Active, true), asset("NEW", Status::Active, false)], &syncs, started, 900).healthy);
+	assert!(get_sync_check(&[asset("NONE", Status::Active, true)], &syncs, Utc::now(), 900).healthy);
+	assert!(!get_sync_check(&[asset("NONE", Status::Active, true)], &syncs, started, 900).healthy);
+}
+
+#[actix_web::test]
+async fn monitor_records_errors_and_jobs() {
+	let monitor: &HealthMonitor = HealthMonitor::instance();
+	monitor.record_error("health_test", "first");
+	monitor.record_error("health_test", "second");
+
+	let messages: Vec<String> = monitor
+		.get_errors()
+		.into_iter()
+		.filter(|error| error.target == "health_test")
+		.map(|error| error.message)
+		.collect();
+	assert_eq!(messages, vec!["second", "first"]);
+
+	monitor.start_job("health_test_job", "0 */1 * * * *");
+	monitor.finish_job("health_test_job", Utc::now());
+	monitor.stop_job("health_test_job");
+
+	let job: HealthJob = monitor.get_jobs().into_iter().find(|job| job.name == "health_test_job").unwrap();
+	assert!(!job.running);
+	assert!(job.last.is_some());
+	assert!(job.duration.is_some());
+}
+
+#[actix_web::test]
+async fn health_is_alive() {
+	let application = test::init_service(App::new().route("/health", web::get().to(health))).await;
+
+	let request = test::TestRequest::get().uri("/health").to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::OK);
+
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert!(body.result);
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn ready_reports_checks() {
+	AppConfig::initialize(&AppConfig::default());
+
+	let database: Database = common::initialize_database("health").await;
+
+	let application = test::init_service(App::new().app_data(web::Data::new(database.clone())).route("/ready", web::get().to(ready))).await;
+
+	let request = test::TestRequest::get().uri("/ready").to_request();
+	let response = test::call_service(&application, request).await;
+	let status: StatusCode = response.status();
+
+	let body: HealthReadyResponse = test::read_body_json(response).await;
+	assert_eq!(status == StatusCode::OK, body.result);
+	assert!(body.checks.iter().any(|check| check.name == "database" && check.healthy));
+
+	database.drop().await.unwrap();
+}
@@ -0,0 +1,184 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use bson::oid::ObjectId;
+use bson::{doc, to_document, Document};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::database::models::{CreateResponse, Timestamp, UserReference};
+use rizky_pratama_tan_api::core::database::services::archive;
+use rizky_pratama_tan_api::core::history::enums::HistoryEntity;
+use rizky_pratama_tan_api::core::history::models::{HistoryChange, HistoryDiffResponse, HistoryListResponse};
+use rizky_pratama_tan_api::core::history::services::{get_diff, get_restore_document};
+use rizky_pratama_tan_api::core::history;
+use rizky_pratama_tan_api::user::enums::UserType;
+use rizky_pratama_tan_api::user::models::{User, UserLoginResponse};
+use rizky_pratama_tan_api::user::repositories::{create_index, find_one_by_username, replace_one};
+use rizky_pratama_tan_api::user;
+use serde_json::{json, Value};
+
+fn initialize_user(city: &str, password: &str) -> Document {
+	let mut document: Document = to_document(&User {
+		username: "history".to_string(),
+		..Default::default()
+	})
+	.unwrap();
+	document.get_document_mut("address").unwrap().insert("city", city);
+	document.get_document_mut("password").unwrap().insert("main", password);
+
+	document
+}
+
+#[actix_web::test]
+async fn diff_reports_nested_fields_and_hides_secrets() {
+	let mut from: Document = initialize_user("Jakarta", "first-hash");
+	from.insert("user_id", ObjectId::new());
+
+	let mut to: Document = initialize_user("Bandung", "second-hash");
+	to.insert("status", "Active");
+
+	let changes: Vec<HistoryChange> = get_diff(&HistoryEntity::User, &from, &to);
+	let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+
+	assert_eq!(fields, vec!["address.city", "status"]);
+	assert_eq!((changes[0].from.clone(), changes[0].to.clone()), (json!("Jakarta"), json!("Bandung")));
+	assert!(get_diff(&HistoryEntity::User, &from, &from).is_empty());
+}
+
+#[actix_web::test]
+async fn archive_keeps_snapshot_modified() {
+	let id: ObjectId = ObjectId::new();
+	let mut user: Document = initialize_user("Jakarta", "hash");
+	user.insert("_id", id);
+	user.insert("modified", to_document(&Timestamp {
+		user: UserReference {
+			username: "editor".to_string(),
+			..Default::default()
+		},
+		..Default::default()
+	}).unwrap());
+
+	let actor: UserReference = UserReference {
+		username: "archiver".to_string(),
+		..Default::default()
+	};
+	let document: Document = archive(&user, Some(actor), "user_id");
+
+	assert!(!document.contains_key("_id"));
+	assert_eq!(document.get_object_id("user_id").unwrap(), id);
+	assert_eq!(document.get_document("modified").unwrap().get_document("user").unwrap().get_str("username").unwrap(), "editor");
+	assert_eq!(document.get_document("archived").unwrap().get_document("user").unwrap().get_str("username").unwrap(), "archiver");
+	assert!(get_restore_document(&HistoryEntity::User, &document, &user).get("archived").is_none());
+}
+
+#[actix_web::test]
+async fn restore_keeps_identity_and_protected_fields() {
+	let id: ObjectId = ObjectId::new();
+
+	let mut version: Document = initialize_user("Jakarta", "old-hash");
+	version.insert("_id", ObjectId::new());
+	version.insert("status", "Active");
+	version.insert("type", "Owner");
+	version.insert("user_id", id);
+
+	let mut current: Document = initialize_user("Bandung", "new-hash");
+	current.insert("_id", id);
+	current.insert("status", "Inactive");
+	current.insert("type", "Member");
+
+	let document: Document = get_restore_document(&HistoryEntity::User, &version, &current);
+
+	assert_eq!(document.get_object_id("_id").unwrap(), id);
+	assert_eq!(document.get_document("address").unwrap().get_str("city").unwrap(), "Jakarta");
+	assert_eq!(document.get_document("password").unwrap().get_str("main").unwrap(), "new-hash");
+	assert_eq!(document.get_str("status").unwrap(), "Inactive");
+	assert_eq!(document.get_str("type").unwrap(), "Member");
+	assert!(!document.contains_key("user_id"));
+	assert!(HistoryEntity::User.is_valid(&document));
+	assert!(!HistoryEntity::User.is_valid(&doc! {"username": "history"}));
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn user_version_is_listed_compared_and_restored() {
+	common::initialize_client("history");
+
+	let database: Database = common::initialize_database("history").await;
+	create_index(&database).await;
+
+	let application = test::init_service(
+		App::new()
+			.app_data(web::Data::new(database.clone()))
+			.service(web::scope("/history").wrap(Authentication::new(Access::Public)).configure(history::routes::config))
+			.service(web::scope("/user").wrap(Authentication::new(Access::Public)).configure(user::routes::config)),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "history")).set_json(json!({"email": "admin@example.com", "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": "admin"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let mut admin: User = find_one_by_username(&database, "admin").await.unwrap();
+	admin._type = UserType::Administrator;
+	replace_one(&database, &admin, None).await.unwrap();
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "history")).set_json(json!({"password": "password", "username": "admin"})).to_request();
+	let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+
+	let request = test::TestRequest::post().uri("/user/create").insert_header(("pld-key", "history")).set_json(json!({"authentication": login.authentication, "contact": {"email": "first@example.com"}, "password": "password", "username": "member"})).to_request();
+	let created: CreateResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(created.result, "{}", created.response);
+
+	let request = test::TestRequest::post().uri("/user/update").insert_header(("pld-key", "history")).set_json(json!({"authentication": login.authentication, "contact": {"email": "second@example.com"}, "username": "member"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let body: Value = json!({"_id": created.id.to_hex(), "authentication": login.authentication, "entity": "User"});
+
+	let request = test::TestRequest::post().uri("/history").insert_header(("pld-key", "history")).set_json(&body).to_request();
+	let list: HistoryListResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(list.data.len(), 1);
+	assert_eq!(list.data[0].archived.clone().unwrap().user.username, "admin");
+	assert!(list.data[0].modified.timestamp < list.data[0].archived.clone().unwrap().timestamp);
+
+	let mut diff: Value = body.clone();
+	diff["from"] = json!(list.data[0].id.to_hex());
+
+	let request = test::TestRequest::post().uri("/history/diff").insert_header(("pld-key", "history")).set_json(&diff).to_request();
+	let response: HistoryDiffResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.data.iter().any(|change| change.field == "contact.email" && change.to == json!("second@example.com")));
+	assert_eq!(response.modified.unwrap().user.username, "admin");
+
+	let mut restore: Value = body.clone();
+	restore["version"] = json!(list.data[0].id.to_hex());
+
+	let request = test::TestRequest::post().uri("/history/restore").insert_header(("pld-key", "history")).set_json(&restore).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+	assert_eq!(find_one_by_username(&database, "member").await.unwrap().contact.email, "first@example.com");
+
+	let mut member: User = find_one_by_username(&database, "member").await.unwrap();
+	member.username = "renamed".to_string();
+	replace_one(&database, &member, None).await.unwrap();
+
+	let request = test::TestRequest::post().uri("/user/create").insert_header(("pld-key", "history")).set_json(json!({"authentication": login.authentication, "contact": {"email": "third@example.com"}, "password": "password", "username": "member"})).to_request();
+	let response: CreateResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/history/restore").insert_header(("pld-key", "history")).set_json(&restore).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::CONFLICT);
+	assert_eq!(find_one_by_username(&database, "renamed").await.unwrap().contact.email, "first@example.com");
+
+	let mut asset: Value = body.clone();
+	asset["entity"] = json!("TradingAsset");
+
+	let request = test::TestRequest::post().uri("/history").insert_header(("pld-key", "history")).set_json(&asset).to_request();
+	let response: HistoryListResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result);
+
+	database.drop().await.unwrap();
+}
@@ -0,0 +1,74 @@
+use chrono::{Duration, Utc};
+use reqwest::header::{HeaderMap, HeaderValue};
+use rizky_pratama_tan_api::integration::enums::IntegrationError;
+use rizky_pratama_tan_api::integration::services::{get_retry_after, redact_body, redact_headers, redact_url};
+
+#[test]
+fn integration_error_classifies_retryable_errors() {
+	assert!(IntegrationError::Network("connection refused".to_string()).is_retryable());
+	assert!(IntegrationError::RateLimit { body: String::new(), retry_after: Some(60) }.is_retryable());
+	assert!(IntegrationError::Status { body: String::new(), status: 502 }.is_retryable());
+	assert!(!IntegrationError::Status { body: String::new(), status: 404 }.is_retryable());
+	assert!(!IntegrationError::Parse { body: String::new(), error: String::new() }.is_retryable());
+}
+
+#[test]
+fn integration_error_classifies_misconfigured_errors() {
+	assert!(IntegrationError::Status { body: String::new(), status: 401 }.is_misconfigured());
+	assert!(!IntegrationError::Status { body: String::new(), status: 404 }.is_misconfigured());
+	assert!(IntegrationError::Status { body: String::new(), status: 404 }.is_not_found());
+	assert!(!IntegrationError::Status { body: String::new(), status: 403 }.is_not_found());
+	assert!(!IntegrationError::Status { body: String::new(), status: 500 }.is_misconfigured());
+	assert!(!IntegrationError::RateLimit { body: String::new(), retry_after: None }.is_misconfigured());
+}
+
+#[test]
+fn retry_after_accepts_seconds_and_http_date() {
+	assert_eq!(get_retry_after("120"), Some(120));
+	assert_eq!(get_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(0));
+	assert_eq!(get_retry_after("invalid"), None);
+
+	let date: String = (Utc::now() + Duration::seconds(120)).format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+	let retry_after: u64 = get_retry_after(&date).unwrap();
+	assert!((118..=120).contains(&retry_after));
+}
+
+#[test]
+fn redact_url_hides_secret_params() {
+	assert_eq!(
+		redact_url("https://eodhd.com/api/intraday/XAUUSD.FOREX?api_token=secret&fmt=json&interval=1m"),
+		"https://eodhd.com/api/intraday/XAUUSD.FOREX?api_token=[REDACTED]&fmt=json&interval=1m"
+	);
+	assert_eq!(
+		redact_url("https://api.massive.com/v2/aggs/ticker/C:XAUUSD/range/1/minute/2025-01-01/2025-01-04?apiKey=secret&multiplier=1"),
+		"https://api.massive.com/v2/aggs/ticker/C:XAUUSD/range/1/minute/2025-01-01/2025-01-04?apiKey=[REDACTED]&multiplier=1"
+	);
+	assert_eq!(redact_url("https://ai.local/trading/data/predict"), "https://ai.local/trading/data/predict");
+}
+
+#[test]
+fn redact_headers_hides_secret_headers() {
+	let mut headers: HeaderMap = HeaderMap::new();
+	headers.insert("content-type", HeaderValue::from_static("application/json"));
+	headers.insert("pld-key", HeaderValue::from_static("secret"));
+
+	let redacted: String = redact_headers(&headers);
+
+	assert!(redacted.contains(r#""pld-key": "[REDACTED]""#));
+	assert!(redacted.contains(r#""content-type": "application/json""#));
+	assert!(!redacted.contains("secret"));
+}
+
+#[test]
+fn redact_body_hides_secret_fields_and_truncates() {
+	assert_eq!(
+		redact_body(r#"{"ticker":"XAUUSD","token":"secret"}"#),
+		r#"{"ticker":"XAUUSD","token":"[REDACTED]"}"#
+	);
+
+	let body: String = format!("[{}]", vec!["1"; 2000].join(","));
+	let redacted: String = redact_body(&body);
+
+	assert!(redacted.starts_with(&body[..1024]));
+	assert!(redacted.ends_with(&format!("... ({} bytes truncated)", body.len() - 1024)));
+}
@@ -0,0 +1,69 @@
+use rizky_pratama_tan_api::core::authentication::services::{match_ip, parse_network, resolve_ip};
+use std::net::IpAddr;
+
+fn list(entries: &[&str]) -> Vec<String> {
+	entries.iter().map(|entry| entry.to_string()).collect()
+}
+
+#[test]
+fn exact_address_and_wildcard_match() {
+	assert!(match_ip("10.0.0.1", &list(&["10.0.0.1"])));
+	assert!(!match_ip("10.0.0.2", &list(&["10.0.0.1"])));
+	assert!(match_ip("203.0.113.9", &list(&["*"])));
+	assert!(!match_ip("10.0.0.1", &[]));
+}
+
+#[test]
+fn ipv4_cidr_matches() {
+	let allow: Vec<String> = list(&["192.168.1.0/24", "10.0.0.0/8"]);
+
+	assert!(match_ip("192.168.1.200", &allow));
+	assert!(match_ip("10.255.0.1", &allow));
+	assert!(!match_ip("192.168.2.1", &allow));
+	assert!(!match_ip("11.0.0.1", &allow));
+}
+
+#[test]
+fn ipv6_address_and_cidr_match() {
+	let allow: Vec<String> = list(&["2001:db8::/32", "::1"]);
+
+	assert!(match_ip("2001:db8:abcd::1", &allow));
+	assert!(match_ip("::1", &allow));
+	assert!(!match_ip("2001:db9::1", &allow));
+	assert!(!match_ip("127.0.0.1", &allow));
+}
+
+#[test]
+fn ipv4_mapped_ipv6_matches_ipv4_entries() {
+	assert!(match_ip("::ffff:10.0.0.1", &list(&["10.0.0.0/24"])));
+	assert!(match_ip("10.0.0.1", &list(&["::ffff:10.0.0.0/120"])));
+}
+
+#[test]
+fn invalid_entries_never_match() {
+	assert!(parse_network("10.0.0.0/33").is_none());
+	assert!(parse_network("localhost").is_none());
+	assert!(!match_ip("10.0.0.1", &list(&["localhost", "10.0.0.0/33"])));
+	assert!(!match_ip("invalid", &list(&["10.0.0.0/8"])));
+}
+
+#[test]
+fn forwarded_header_is_ignored_from_untrusted_peer() {
+	let peer: Option<IpAddr> = "203.0.113.9".parse().ok();
+
+	assert_eq!(resolve_ip(peer, Some("10.0.0.1"), &[]), "203.0.113.9");
+	assert_eq!(resolve_ip(peer, Some("10.0.0.1"), &list(&["127.0.0.1"])), "203.0.113.9");
+	assert_eq!(resolve_ip(None, Some("10.0.0.1"), &[]), "");
+}
+
+#[test]
+fn forwarded_header_is_honoured_from_trusted_proxy() {
+	let peer: Option<IpAddr> = "127.0.0.1".parse().ok();
+	let proxies: Vec<String> = list(&["127.0.0.1", "172.16.0.0/12"]);
+
+	assert_eq!(resolve_ip(peer, Some("198.51.100.7"), &proxies), "198.51.100.7");
+	assert_eq!(resolve_ip(peer, Some("1.1.1.1, 198.51.100.7, 172.16.0.3"), &proxies), "198.51.100.7");
+	assert_eq!(resolve_ip(peer, Some("2001:db8::1"), &proxies), "2001:db8::1");
+	assert_eq!(resolve_ip(peer, Some("invalid"), &proxies), "127.0.0.1");
+	assert_eq!(resolve_ip(peer, None, &proxies), "127.0.0.1");
+}
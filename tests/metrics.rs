@@ -0,0 +1,135 @@
+use actix_web::http::{Method, StatusCode};
+use actix_web::test::{call_service, init_service, read_body, TestRequest};
+use actix_web::{web, App, HttpResponse};
+use rizky_pratama_tan_api::core::authentication::enums::{Access, AuthenticationError};
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::encryption::hmac::is_equal;
+use rizky_pratama_tan_api::core::metrics::enums::Metric;
+use rizky_pratama_tan_api::core::metrics::middleware::RequestMetrics;
+use rizky_pratama_tan_api::core::metrics::models::MetricHistogram;
+use rizky_pratama_tan_api::core::metrics::services::{get_labels, get_method, metrics, Metrics};
+use rizky_pratama_tan_api::integration::enums::IntegrationError;
+
+#[actix_web::test]
+async fn counter_accumulates_per_label_set() {
+	let metrics: &Metrics = Metrics::instance();
+	metrics.increment(&Metric::TradingCandlesInserted, &[("asset", "METRICS_A")], 3.0);
+	metrics.increment(&Metric::TradingCandlesInserted, &[("asset", "METRICS_A")], 2.0);
+	metrics.increment(&Metric::TradingCandlesInserted, &[("asset", "METRICS_B")], 1.0);
+
+	assert_eq!(metrics.get_counter(&Metric::TradingCandlesInserted, &[("asset", "METRICS_A")]), 5.0);
+	assert_eq!(metrics.get_counter(&Metric::TradingCandlesInserted, &[("asset", "METRICS_B")]), 1.0);
+}
+
+#[actix_web::test]
+async fn histogram_is_rendered_cumulatively() {
+	let metrics: &Metrics = Metrics::instance();
+	metrics.observe(&Metric::SchedulerJobDuration, &[("job", "metrics_test")], 0.02);
+	metrics.observe(&Metric::SchedulerJobDuration, &[("job", "metrics_test")], 3.0);
+
+	let histogram: MetricHistogram = metrics.get_histogram(&Metric::SchedulerJobDuration, &[("job", "metrics_test")]);
+	assert_eq!(histogram.count, 2);
+	assert!((histogram.sum - 3.02).abs() < 1e-9);
+
+	let output: String = metrics.render();
+	assert!(output.contains("# TYPE scheduler_job_duration_seconds histogram"));
+	assert!(output.contains("scheduler_job_duration_seconds_bucket{job=\"metrics_test\",le=\"0.01\"} 0"));
+	assert!(output.contains("scheduler_job_duration_seconds_bucket{job=\"metrics_test\",le=\"0.025\"} 1"));
+	assert!(output.contains("scheduler_job_duration_seconds_bucket{job=\"metrics_test\",le=\"5\"} 2"));
+	assert!(output.contains("scheduler_job_duration_seconds_bucket{job=\"metrics_test\",le=\"+Inf\"} 2"));
+	assert!(output.contains("scheduler_job_duration_seconds_count{job=\"metrics_test\"} 2"));
+}
+
+#[actix_web::test]
+async fn label_values_are_escaped() {
+	assert_eq!(get_labels(&[("route", "a\"b\\c\nd")]), "route=\"a\\\"b\\\\c\\nd\"");
+	assert_eq!(get_labels(&[("method", "GET"), ("status", "200")]), "method=\"GET\",status=\"200\"");
+}
+
+#[actix_web::test]
+async fn reasons_and_kinds_are_stable() {
+	assert_eq!(AuthenticationError::UnauthorizedKey("key".to_string()).get_reason(), "unauthorized_key");
+	assert_eq!(AuthenticationError::TokenReplayed.get_reason(), "token_replayed");
+	assert_eq!(IntegrationError::Network(String::new()).get_kind(), "network");
+	assert_eq!(IntegrationError::Status { body: String::new(), status: 500 }.get_kind(), "status");
+}
+
+#[actix_web::test]
+async fn requests_are_recorded_by_route() {
+	let application = init_service(
+		App::new()
+			.wrap(RequestMetrics)
+			.route("/metrics_test/{id}", web::get().to(HttpResponse::Ok))
+			.service(web::scope("/metrics_private").wrap(Authentication::new(Access::Private)).route("", web::post().to(HttpResponse::Ok))),
+	)
+	.await;
+
+	for uri in ["/metrics_test/1", "/metrics_test/2"] {
+		let request = TestRequest::get().uri(uri).to_request();
+		assert_eq!(call_service(&application, request).await.status(), StatusCode::OK);
+	}
+
+	let labels: [(&str, &str); 3] = [("method", "GET"), ("route", "/metrics_test/{id}"), ("status", "200")];
+	assert_eq!(Metrics::instance().get_counter(&Metric::HttpRequests, &labels), 2.0);
+	assert_eq!(
+		Metrics::instance().get_histogram(&Metric::HttpRequestDuration, &[("method", "GET"), ("route", "/metrics_test/{id}")]).count,
+		2
+	);
+
+	let failures: f64 = Metrics::instance().get_counter(&Metric::AuthenticationFailures, &[("reason", "unauthorized_key")]);
+	let request = TestRequest::post().uri("/metrics_private").to_request();
+	assert_eq!(call_service(&application, request).await.status(), StatusCode::UNAUTHORIZED);
+	assert!(Metrics::instance().get_counter(&Metric::AuthenticationFailures, &[("reason", "unauthorized_key")]) > failures);
+	assert_eq!(
+		Metrics::instance().get_counter(&Metric::HttpRequests, &[("method", "POST"), ("route", "/metrics_private"), ("status", "401")]),
+		1.0
+	);
+}
+
+#[test]
+fn token_comparison_checks_every_byte() {
+	assert!(is_equal("metrics-secret", "metrics-secret"));
+	assert!(!is_equal("metrics-secret", "metrics-secreT"));
+	assert!(!is_equal("metrics-secret", "metrics"));
+	assert!(!is_equal("", "metrics-secret"));
+}
+
+#[test]
+fn method_label_collapses_unknown_methods() {
+	assert_eq!(get_method(&Method::GET), "GET");
+	assert_eq!(get_method(&Method::PATCH), "PATCH");
+	assert_eq!(get_method(&Method::from_bytes(b"PROPFIND").unwrap()), "other");
+	assert_eq!(get_method(&Method::from_bytes(b"X-RANDOM-1234").unwrap()), "other");
+}
+
+#[actix_web::test]
+async fn endpoint_requires_configured_token() {
+	let application = init_service(App::new().route("/metrics", web::get().to(metrics))).await;
+
+	let mut config: AppConfig = AppConfig::instance();
+	config.metrics.token = String::new();
+	AppConfig::initialize(&config);
+
+	let request = TestRequest::get().uri("/metrics").to_request();
+	assert_eq!(call_service(&application, request).await.status(), StatusCode::UNAUTHORIZED);
+
+	let request = TestRequest::get().uri("/metrics").insert_header(("authorization", "Bearer ")).to_request();
+	assert_eq!(call_service(&application, request).await.status(), StatusCode::UNAUTHORIZED);
+
+	config.metrics.token = "metrics-secret".to_string();
+	AppConfig::initialize(&config);
+
+	let request = TestRequest::get().uri("/metrics").to_request();
+	assert_eq!(call_service(&application, request).await.status(), StatusCode::UNAUTHORIZED);
+
+	let request = TestRequest::get().uri("/metrics").insert_header(("authorization", "Bearer metrics-secreT")).to_request();
+	assert_eq!(call_service(&application, request).await.status(), StatusCode::UNAUTHORIZED);
+
+	let request = TestRequest::get().uri("/metrics").insert_header(("authorization", "Bearer metrics-secret")).to_request();
+	let response = call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::OK);
+
+	let body: String = String::from_utf8(read_body(response).await.to_vec()).unwrap();
+	assert!(body.contains("# TYPE http_requests_total counter"));
+}
@@ -0,0 +1,221 @@
+mod common;
+
+use actix_web::http::header::RETRY_AFTER;
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use actix_web::dev::Service;
+use actix_web::HttpMessage;
+use mongodb::Database;
+use rizky_pratama_tan_api::client::models::Client;
+use rizky_pratama_tan_api::core::authentication::models::AuthenticatedClient;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::rate_limit::enums::{RateLimitError, RateLimitKey};
+use rizky_pratama_tan_api::core::rate_limit::middleware::RateLimit;
+use rizky_pratama_tan_api::core::rate_limit::repositories::{create_index, find_one_and_update_bucket, find_one_and_update_quota};
+use rizky_pratama_tan_api::core::rate_limit::services::{check, get_config};
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::config::enums::Backend;
+use rizky_pratama_tan_api::core::config::models::{AppConfig, RateLimitScopeConfig};
+use serenity::futures::future::join_all;
+use std::sync::Mutex;
+
+static CONFIG: Mutex<()> = Mutex::new(());
+
+fn configure(update: impl FnOnce(&mut AppConfig)) {
+	let _lock = CONFIG.lock().unwrap();
+
+	let mut config: AppConfig = AppConfig::instance();
+	update(&mut config);
+	AppConfig::initialize(&config);
+}
+
+fn initialize(scope: &str, capacity: f64, refill: f64, quota: u64) {
+	configure(|config| {
+		config.rate_limit.scopes.insert(
+			scope.to_string(),
+			RateLimitScopeConfig {
+				capacity,
+				quota,
+				refill: refill / 60.0,
+			},
+		);
+	});
+}
+
+async fn ok() -> HttpResponse {
+	HttpResponse::Ok().finish()
+}
+
+#[actix_web::test]
+async fn config_is_read_per_scope() {
+	initialize("config", 5.0, 120.0, 100);
+
+	let config = get_config("config");
+
+	assert_eq!(config.capacity, 5.0);
+	assert_eq!(config.refill, 2.0);
+	assert_eq!(config.quota, 100);
+	assert_eq!(get_config("unknown").capacity, 0.0);
+}
+
+#[tokio::test]
+async fn unconfigured_scope_is_unlimited() {
+	for _ in 0..100 {
+		assert!(check(None, "unlimited", "client", "10.0.0.1").await.is_ok());
+	}
+}
+
+#[tokio::test]
+async fn bucket_is_limited_per_client_and_ip() {
+	initialize("bucket", 2.0, 1.0, 0);
+
+	assert!(check(None, "bucket", "first", "10.0.0.1").await.is_ok());
+	assert!(check(None, "bucket", "first", "10.0.0.2").await.is_ok());
+
+	match check(None, "bucket", "first", "10.0.0.3").await {
+		Err(RateLimitError::TooManyRequests { retry_after }) => assert!(retry_after > 0 && retry_after <= 60),
+		result => panic!("{:?}", result),
+	}
+
+	assert!(check(None, "bucket", "second", "10.0.0.4").await.is_ok());
+	assert!(check(None, "bucket", "", "10.0.0.4").await.is_ok());
+	assert!(check(None, "bucket", "", "10.0.0.4").await.is_ok());
+	assert!(check(None, "bucket", "", "10.0.0.4").await.is_err());
+}
+
+#[tokio::test]
+async fn daily_quota_is_enforced() {
+	initialize("quota", 0.0, 0.0, 3);
+
+	for _ in 0..3 {
+		assert!(check(None, "quota", "client", "10.0.0.1").await.is_ok());
+	}
+
+	match check(None, "quota", "client", "10.0.0.1").await {
+		Err(RateLimitError::QuotaExceeded { retry_after }) => assert!(retry_after > 0 && retry_after <= 86400),
+		result => panic!("{:?}", result),
+	}
+
+	assert!(check(None, "quota", "other", "10.0.0.1").await.is_ok());
+}
+
+#[actix_web::test]
+async fn middleware_responds_with_retry_after() {
+	initialize("middleware", 1.0, 1.0, 0);
+
+	let application = test::init_service(App::new().service(web::scope("/site").wrap(RateLimit::new("middleware", RateLimitKey::Client)).route("/echo", web::post().to(ok)))).await;
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "client")).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::OK);
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "client")).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+	assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "60");
+
+	let body: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(body.response, "Too many requests.");
+	assert!(!body.result);
+}
+
+#[actix_web::test]
+async fn authenticated_client_has_own_bucket() {
+	initialize("authenticated", 1.0, 1.0, 0);
+
+	let application = test::init_service(
+		App::new().service(
+			web::scope("/site")
+				.wrap(RateLimit::new("authenticated", RateLimitKey::Client))
+				.wrap_fn(|request, service| {
+					let key: String = request.headers().get("test-client").and_then(|value| value.to_str().ok()).unwrap_or_default().to_string();
+					request.extensions_mut().insert(AuthenticatedClient {
+						client: Client {
+							key,
+							..Default::default()
+						},
+					});
+
+					service.call(request)
+				})
+				.route("/echo", web::post().to(ok)),
+		),
+	)
+	.await;
+
+	for (key, status) in [("first", StatusCode::OK), ("second", StatusCode::OK), ("first", StatusCode::TOO_MANY_REQUESTS)] {
+		let request = test::TestRequest::post().uri("/site/echo").insert_header(("test-client", key)).to_request();
+		assert_eq!(test::call_service(&application, request).await.status(), status, "{}", key);
+	}
+}
+
+#[actix_web::test]
+async fn unauthenticated_key_header_is_ignored() {
+	initialize("forged", 1.0, 1.0, 0);
+
+	let application = test::init_service(App::new().service(web::scope("/site").wrap(RateLimit::new("forged", RateLimitKey::Client)).route("/echo", web::post().to(ok)))).await;
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "first")).to_request();
+	assert_eq!(test::call_service(&application, request).await.status(), StatusCode::OK);
+
+	let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "second")).to_request();
+	assert_eq!(test::call_service(&application, request).await.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[actix_web::test]
+async fn ip_limit_applies_before_authentication() {
+	initialize("unauthenticated_ip", 2.0, 1.0, 0);
+	initialize("unauthenticated", 100.0, 100.0, 0);
+
+	let application = test::init_service(
+		App::new().service(
+			web::scope("/site")
+				.wrap(RateLimit::new("unauthenticated", RateLimitKey::Client))
+				.wrap(Authentication::new(Access::Public))
+				.wrap(RateLimit::new("unauthenticated", RateLimitKey::Ip))
+				.route("/echo", web::post().to(ok)),
+		),
+	)
+	.await;
+
+	for status in [StatusCode::UNAUTHORIZED, StatusCode::UNAUTHORIZED, StatusCode::TOO_MANY_REQUESTS] {
+		let request = test::TestRequest::post().uri("/site/echo").insert_header(("pld-key", "unknown")).insert_header(("pld-token", "invalid")).to_request();
+		assert_eq!(test::call_service(&application, request).await.status(), status);
+	}
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn mongodb_first_hit_race_is_not_allowed_through() {
+	let database: Database = common::initialize_database("rate_limit_race").await;
+	create_index(&database).await;
+
+	configure(|config| config.rate_limit.backend = Backend::Mongodb);
+
+	initialize("race", 5.0, 0.0, 0);
+
+	let results: Vec<Result<(), RateLimitError>> = join_all((0..10).map(|_| check(Some(&database), "race", "client", "10.0.0.1"))).await;
+	assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 5);
+
+	database.drop().await.unwrap();
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn mongodb_bucket_and_quota_are_atomic() {
+	let database: Database = common::initialize_database("rate_limit").await;
+	create_index(&database).await;
+
+	let expired: DateTime<Utc> = Utc::now() + Duration::minutes(1);
+
+	assert!(find_one_and_update_bucket(&database, "bucket", 2.0, 0.0, expired).await.unwrap().unwrap().allowed);
+	assert!(find_one_and_update_bucket(&database, "bucket", 2.0, 0.0, expired).await.unwrap().unwrap().allowed);
+	assert!(!find_one_and_update_bucket(&database, "bucket", 2.0, 0.0, expired).await.unwrap().unwrap().allowed);
+
+	assert_eq!(find_one_and_update_quota(&database, "quota", expired).await.unwrap().unwrap().count, 1);
+	assert_eq!(find_one_and_update_quota(&database, "quota", expired).await.unwrap().unwrap().count, 2);
+
+	database.drop().await.unwrap();
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use actix_web::{test, web, App};
+use bson::oid::ObjectId;
+use chrono::{Duration, Utc};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::user::log::enums::Type;
+use rizky_pratama_tan_api::user::log::models::{UserLog, UserLogBrowser, UserLogOs};
+use rizky_pratama_tan_api::user::models::UserLoginResponse;
+use rizky_pratama_tan_api::user::repositories::create_index;
+use rizky_pratama_tan_api::user::routes;
+use rizky_pratama_tan_api::user::security::enums::UserSecurityEventType;
+use rizky_pratama_tan_api::user::security::models::UserSecurityEventListResponse;
+use rizky_pratama_tan_api::user::security::services::detect_anomaly;
+use rizky_pratama_tan_api::user::services::get_country_code;
+use serde_json::json;
+use std::time::Duration as StdDuration;
+
+fn initialize_log(ip: &str, browser: &str, country: &str, age: Duration) -> UserLog {
+	let mut log: UserLog = UserLog {
+		browser: UserLogBrowser {
+			family: browser.to_string(),
+			..Default::default()
+		},
+		country: country.to_string(),
+		id: ObjectId::new(),
+		ip: ip.to_string(),
+		os: UserLogOs {
+			os: "Windows".to_string(),
+			..Default::default()
+		},
+		_type: Type::Login,
+		..Default::default()
+	};
+	log.created.timestamp = Utc::now() - age;
+
+	log
+}
+
+fn get_types(history: &[UserLog], log: &UserLog) -> Vec<UserSecurityEventType> {
+	detect_anomaly(history, log, 7200).into_iter().map(|(_type, _)| _type).collect()
+}
+
+#[actix_web::test]
+async fn first_and_familiar_logins_are_not_flagged() {
+	let log: UserLog = initialize_log("10.0.0.1", "Chrome", "ID", Duration::zero());
+
+	assert!(get_types(&[], &log).is_empty());
+	assert!(get_types(std::slice::from_ref(&log), &log).is_empty());
+	assert!(get_types(&[initialize_log("10.0.0.1", "Chrome", "ID", Duration::days(1))], &log).is_empty());
+}
+
+#[actix_web::test]
+async fn new_ip_and_device_are_flagged() {
+	let history: Vec<UserLog> = vec![initialize_log("10.0.0.1", "Chrome", "ID", Duration::days(1))];
+
+	assert_eq!(get_types(&history, &initialize_log("10.0.0.2", "Chrome", "ID", Duration::zero())), vec![UserSecurityEventType::NewIp]);
+	assert_eq!(get_types(&history, &initialize_log("10.0.0.1", "Firefox", "ID", Duration::zero())), vec![UserSecurityEventType::NewDevice]);
+}
+
+#[actix_web::test]
+async fn impossible_travel_is_flagged_within_window() {
+	let log: UserLog = initialize_log("10.0.0.1", "Chrome", "US", Duration::zero());
+
+	let events: Vec<(UserSecurityEventType, String)> = detect_anomaly(&[initialize_log("10.0.0.1", "Chrome", "ID", Duration::minutes(30))], &log, 7200);
+	assert_eq!(events, vec![(UserSecurityEventType::ImpossibleTravel, "ID".to_string())]);
+
+	assert!(get_types(&[initialize_log("10.0.0.1", "Chrome", "ID", Duration::hours(3))], &log).is_empty());
+	assert!(get_types(&[initialize_log("10.0.0.1", "Chrome", "", Duration::minutes(30))], &log).is_empty());
+}
+
+#[actix_web::test]
+async fn country_header_requires_trusted_proxy() {
+	let mut config: AppConfig = AppConfig::instance();
+	config.authentication.trusted_proxies = vec!["10.0.0.0/8".to_string()];
+	config.user.security.country_header = "cf-ipcountry".to_string();
+	AppConfig::initialize(&config);
+
+	let trusted = test::TestRequest::default().peer_addr("10.0.0.5:443".parse().unwrap()).insert_header(("cf-ipcountry", "id")).to_http_request();
+	assert_eq!(get_country_code(&trusted), "ID");
+
+	let direct = test::TestRequest::default().peer_addr("203.0.113.9:443".parse().unwrap()).insert_header(("cf-ipcountry", "ID")).to_http_request();
+	assert_eq!(get_country_code(&direct), "");
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn security_events_are_recorded_and_acknowledged() {
+	common::initialize_client("security");
+
+	let database: Database = common::initialize_database("security").await;
+	create_index(&database).await;
+
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "security")).set_json(json!({"email": "security@example.com", "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": "security"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let mut authentication: String = String::new();
+
+	for user_agent in ["Mozilla/5.0 (Windows NT 10.0) Chrome/120.0 Safari/537.36", "Mozilla/5.0 (Windows NT 10.0; rv:121.0) Gecko/20100101 Firefox/121.0"] {
+		let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "security")).insert_header(("user-agent", user_agent)).set_json(json!({"password": "password", "username": "security"})).to_request();
+		let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+		authentication = login.authentication;
+
+		tokio::time::sleep(StdDuration::from_millis(500)).await;
+	}
+
+	let request = test::TestRequest::post().uri("/user/me/security").insert_header(("pld-key", "security")).set_json(json!({"acknowledged": false, "authentication": authentication})).to_request();
+	let list: UserSecurityEventListResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(list.data.len(), 1);
+	assert_eq!(list.data[0]._type, UserSecurityEventType::NewDevice);
+
+	let request = test::TestRequest::post().uri("/user/me/security/acknowledge").insert_header(("pld-key", "security")).set_json(json!({"_id": list.data[0].id.to_hex(), "authentication": authentication})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/me/security").insert_header(("pld-key", "security")).set_json(json!({"acknowledged": false, "authentication": authentication})).to_request();
+	let list: UserSecurityEventListResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(list.data.is_empty());
+
+	database.drop().await.unwrap();
+}
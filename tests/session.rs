@@ -0,0 +1,123 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use chrono::{Duration, Utc};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::user::log::enums::{Platform, Type};
+use rizky_pratama_tan_api::user::log::models::UserLog;
+use rizky_pratama_tan_api::user::log::services::{get_active_sessions, parse_user_agent};
+use rizky_pratama_tan_api::user::models::{UserLoginResponse, UserSessionListResponse};
+use rizky_pratama_tan_api::user::repositories::create_index;
+use rizky_pratama_tan_api::user::routes;
+use serde_json::json;
+
+const CHROME_WINDOWS: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.6099.110 Safari/537.36";
+const SAFARI_IPHONE: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.2 Mobile/15E148 Safari/604.1";
+const FIREFOX_ANDROID_TABLET: &str = "Mozilla/5.0 (Android 14; Tablet; rv:121.0) Gecko/121.0 Firefox/121.0";
+
+fn initialize_log(authentication: &str, _type: Type) -> UserLog {
+	let mut log: UserLog = UserLog {
+		authentication: authentication.to_string(),
+		_type,
+		..Default::default()
+	};
+	log.created.timestamp = Utc::now() - Duration::minutes(1);
+
+	log
+}
+
+#[actix_web::test]
+async fn user_agent_is_parsed() {
+	let (browser, device, os, platform) = parse_user_agent(CHROME_WINDOWS);
+	assert_eq!((browser.family.as_str(), browser.version.as_str()), ("Chrome", "120.0.6099.110"));
+	assert_eq!((os.os.as_str(), os.version.as_str()), ("Windows", "10"));
+	assert!(device.pc && !device.mobile && !device.touch_capable);
+	assert_eq!(platform, Platform::Desktop);
+
+	let (browser, device, os, platform) = parse_user_agent(SAFARI_IPHONE);
+	assert_eq!((browser.family.as_str(), browser.version.as_str()), ("Safari", "17.2"));
+	assert_eq!((os.os.as_str(), os.version.as_str()), ("iOS", "17.2"));
+	assert!(device.mobile && device.touch_capable && !device.pc);
+	assert_eq!(platform, Platform::Mobile);
+
+	let (browser, device, os, _) = parse_user_agent(FIREFOX_ANDROID_TABLET);
+	assert_eq!((browser.family.as_str(), os.os.as_str(), os.version.as_str()), ("Firefox", "Android", "14"));
+	assert!(device.tablet && !device.mobile);
+
+	let (browser, device, _, _) = parse_user_agent("curl/8.4.0");
+	assert_eq!(browser.family, "Other");
+	assert!(device.bot && !device.pc);
+}
+
+#[actix_web::test]
+async fn logged_out_sessions_are_not_active() {
+	let logs: Vec<UserLog> = vec![
+		initialize_log("first", Type::Login),
+		initialize_log("second", Type::Login),
+		initialize_log("second", Type::Logout),
+	];
+
+	let sessions: Vec<UserLog> = get_active_sessions(&logs);
+	assert_eq!(sessions.len(), 1);
+	assert_eq!(sessions[0].authentication, "first");
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn sessions_are_listed_and_revoked() {
+	common::initialize_client("session");
+
+	let database: Database = common::initialize_database("session").await;
+	create_index(&database).await;
+
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "session")).set_json(json!({"email": "session@example.com", "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": "session"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let mut authentication: Vec<String> = Vec::new();
+
+	for user_agent in [CHROME_WINDOWS, SAFARI_IPHONE, FIREFOX_ANDROID_TABLET] {
+		let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "session")).insert_header(("user-agent", user_agent)).set_json(json!({"password": "password", "username": "session"})).to_request();
+		let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+		authentication.push(login.authentication);
+	}
+
+	let request = test::TestRequest::post().uri("/user/me/session").insert_header(("pld-key", "session")).set_json(json!({"authentication": authentication[0]})).to_request();
+	let list: UserSessionListResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(list.data.len(), 3);
+	assert_eq!(list.data.iter().filter(|session| session.current).count(), 1);
+
+	let iphone = list.data.iter().find(|session| session.os.os == "iOS").unwrap();
+	assert_eq!(iphone.platform, Platform::Mobile);
+
+	let request = test::TestRequest::post().uri("/user/me/session/revoke").insert_header(("pld-key", "session")).set_json(json!({"_id": iphone.id.to_hex(), "authentication": authentication[0]})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/me/session").insert_header(("pld-key", "session")).set_json(json!({"authentication": authentication[1]})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+	let request = test::TestRequest::post().uri("/user/me/session/revoke/other").insert_header(("pld-key", "session")).set_json(json!({"authentication": authentication[0]})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/me/session").insert_header(("pld-key", "session")).set_json(json!({"authentication": authentication[0]})).to_request();
+	let list: UserSessionListResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(list.data.len(), 1);
+
+	database.drop().await.unwrap();
+}
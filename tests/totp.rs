@@ -0,0 +1,43 @@
+use data_encoding::BASE32_NOPAD;
+use rizky_pratama_tan_api::core::encryption::totp::{generate_code, generate_secret, get_step, get_uri, verify_code};
+
+const SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+#[test]
+fn code_matches_rfc_6238_vectors() {
+	assert_eq!(BASE32_NOPAD.decode(SECRET.as_bytes()).unwrap(), b"12345678901234567890");
+
+	for (time, code) in [(59, "287082"), (1111111109, "081804"), (1234567890, "005924"), (2000000000, "279037")] {
+		assert_eq!(generate_code(SECRET, time / 30), code);
+	}
+}
+
+#[test]
+fn current_code_is_verified_once() {
+	let secret: String = generate_secret();
+	let step: i64 = get_step();
+
+	assert_eq!(secret.len(), 32);
+
+	let verified: i64 = verify_code(&secret, &generate_code(&secret, step), 0).unwrap();
+	assert!((step..=step + 1).contains(&verified));
+	assert!(verify_code(&secret, &generate_code(&secret, step), verified).is_none());
+	assert!(verify_code(&secret, &generate_code(&secret, step - 3), 0).is_none());
+}
+
+#[test]
+fn malformed_code_is_rejected() {
+	let secret: String = generate_secret();
+
+	for code in ["", "12345", "1234567", "abcdef"] {
+		assert!(verify_code(&secret, code, 0).is_none());
+	}
+}
+
+#[test]
+fn uri_is_encoded() {
+	assert_eq!(
+		get_uri("Rizky Pratama Tan", "user@example.com", SECRET),
+		"otpauth://totp/Rizky%20Pratama%20Tan:user%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Rizky%20Pratama%20Tan&algorithm=SHA1&digits=6&period=30"
+	);
+}
@@ -0,0 +1,456 @@
+mod common;
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse};
+use chrono::{Duration, Utc};
+use mongodb::Database;
+use rizky_pratama_tan_api::core::authentication::enums::Access;
+use rizky_pratama_tan_api::core::authentication::middleware::Authentication;
+use rizky_pratama_tan_api::core::authentication::models::AuthenticatedUser;
+use bson::{doc, Document};
+use rizky_pratama_tan_api::core::base::enums::{Country, Status, Version};
+use rizky_pratama_tan_api::core::base::models::BaseResponse;
+use rizky_pratama_tan_api::core::config::models::AppConfig;
+use rizky_pratama_tan_api::core::encryption::hmac::digest;
+use rizky_pratama_tan_api::core::encryption::password::{hash_password, is_hashed, verify_password};
+use rizky_pratama_tan_api::user::log::enums::Type;
+use rizky_pratama_tan_api::user::log::models::UserLog;
+use rizky_pratama_tan_api::core::encryption::totp::{generate_code, get_step};
+use rizky_pratama_tan_api::user::enums::UserType;
+use rizky_pratama_tan_api::user::models::{
+	User, UserContact, UserData, UserListRequest, UserListResponse, UserLoginResponse, UserPassword, UserProfileAddress,
+	UserProfileRequest, UserResponse, UserTotpEnrollResponse, UserTotpVerifyResponse,
+};
+use rizky_pratama_tan_api::user::repositories::{create_index, find_one_by_username, replace_one, update_password_attempt, update_totp_last, update_totp_recovery};
+use rizky_pratama_tan_api::user::routes;
+use rizky_pratama_tan_api::user::services::{get_country, get_session_expired, get_skip, get_user_filter, is_session_active, validate_profile};
+use serde_json::{json, Value};
+
+fn initialize_log(_type: Type, remember: bool, age: Duration) -> UserLog {
+	let mut log: UserLog = UserLog {
+		remember,
+		_type,
+		..Default::default()
+	};
+	log.created.timestamp = Utc::now() - age;
+
+	log
+}
+
+async fn session(user: AuthenticatedUser) -> HttpResponse {
+	HttpResponse::Ok().json(json!({"username": user.log.user.username}))
+}
+
+#[actix_web::test]
+async fn session_lifetime_honours_remember() {
+	assert!(is_session_active(&initialize_log(Type::Login, false, Duration::hours(1))));
+	assert!(!is_session_active(&initialize_log(Type::Login, false, Duration::days(2))));
+	assert!(is_session_active(&initialize_log(Type::Login, true, Duration::days(2))));
+	assert!(!is_session_active(&initialize_log(Type::Login, true, Duration::days(31))));
+	assert!(!is_session_active(&initialize_log(Type::Logout, true, Duration::hours(1))));
+
+	let log: UserLog = initialize_log(Type::Login, false, Duration::zero());
+	assert_eq!(get_session_expired(&log), log.created.timestamp + Duration::days(1));
+}
+
+#[actix_web::test]
+async fn password_is_hashed_with_argon2id() {
+	let hash: String = hash_password("password");
+
+	assert!(is_hashed(&hash));
+	assert_ne!(hash, hash_password("password"));
+	assert!(verify_password("password", &hash));
+	assert!(!verify_password("Password", &hash));
+	assert!(!verify_password("", ""));
+}
+
+#[actix_web::test]
+async fn legacy_plaintext_password_is_verified() {
+	assert!(!is_hashed("password"));
+	assert!(verify_password("password", "password"));
+	assert!(!verify_password("other", "password"));
+}
+
+#[actix_web::test]
+async fn user_filter_matches_search_status_and_type() {
+	let filter: Document = get_user_filter(&UserListRequest {
+		search: Some(" a.b ".to_string()),
+		status: Some(Status::Active),
+		_type: Some(UserType::Member),
+		..Default::default()
+	});
+
+	assert_eq!(filter.get_array("$or").unwrap().len(), 4);
+	assert!(filter.get_array("$or").unwrap()[0].to_string().contains("a\\.b"));
+	assert_eq!(filter.get_str("status").unwrap(), "Active");
+	assert_eq!(filter.get_str("type").unwrap(), "Member");
+	assert_eq!(get_user_filter(&UserListRequest::default()), doc! {});
+}
+
+#[actix_web::test]
+async fn user_list_skip_does_not_overflow() {
+	assert_eq!(get_skip(1, 20), 0);
+	assert_eq!(get_skip(3, 20), 40);
+	assert_eq!(get_skip(u64::MAX, 100), i64::MAX as u64);
+}
+
+#[actix_web::test]
+async fn user_data_does_not_expose_password() {
+	let user: User = User {
+		password: UserPassword {
+			main: "secret-hash".to_string(),
+			..Default::default()
+		},
+		username: "user".to_string(),
+		..Default::default()
+	};
+
+	let data: Value = serde_json::to_value(UserData::from(&user)).unwrap();
+
+	assert_eq!(data["username"], json!("user"));
+	assert!(!data.to_string().contains("secret-hash"));
+}
+
+#[actix_web::test]
+async fn profile_fields_are_validated() {
+	let contact = |email: &str, phone: &str| UserProfileRequest {
+		contact: Some(UserContact {
+			email: email.to_string(),
+			phone: phone.to_string(),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	assert_eq!(validate_profile(&contact("user@example.com", "+62 812-3456-7890")), None);
+	assert_eq!(validate_profile(&contact("user@example.com", "")), None);
+	assert_eq!(validate_profile(&contact("user", "")).as_deref(), Some("Invalid email address."));
+	assert_eq!(validate_profile(&contact("user@example.com", "12ab")).as_deref(), Some("Invalid phone number."));
+	assert_eq!(validate_profile(&contact("user@example.com", "+1234567890123456")).as_deref(), Some("Invalid phone number."));
+
+	let address: UserProfileRequest = UserProfileRequest {
+		address: Some(UserProfileAddress {
+			country: "Atlantis".to_string(),
+			..Default::default()
+		}),
+		..Default::default()
+	};
+
+	assert_eq!(validate_profile(&address).as_deref(), Some("Invalid country."));
+	assert_eq!(get_country("unitedstates"), Some(Country::UnitedStates));
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn register_login_and_logout() {
+	common::initialize_client("user");
+
+	let database: Database = common::initialize_database("user").await;
+	create_index(&database).await;
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(AppConfig::instance())).app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config)
+				.route("/session", web::post().to(session)),
+		),
+	)
+	.await;
+
+	let register: Value = json!({"email": "user@example.com", "name": {"first": "User", "last": "", "middle": ""}, "password": "password", "username": "user"});
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "user")).set_json(&register).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "user")).set_json(&register).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::CONFLICT);
+	let response: BaseResponse = test::read_body_json(response).await;
+	assert_eq!((response.result, response.response.as_str()), (false, "Username already exists."));
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "wrong", "username": "user"})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "password", "remember": true, "username": "user"})).to_request();
+	let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(login.result);
+	assert_eq!(login.authentication.len(), 64);
+
+	let request = test::TestRequest::post().uri("/user/session").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication})).to_request();
+	let response: Value = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(response["username"], "user");
+
+	let request = test::TestRequest::post().uri("/user/logout").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result);
+
+	let request = test::TestRequest::post().uri("/user/session").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+	database.drop().await.unwrap();
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn password_lockout_change_and_reset() {
+	let mut config: AppConfig = AppConfig::instance();
+	config.user.password.attempt = 3;
+	AppConfig::initialize(&config);
+
+	common::initialize_client("user");
+
+	let database: Database = common::initialize_database("user").await;
+	create_index(&database).await;
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(AppConfig::instance())).app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "user")).set_json(json!({"email": "password@example.com", "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": "password"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let user: User = find_one_by_username(&database, "password").await.unwrap();
+	assert!(is_hashed(&user.password.main));
+
+	for _ in 0..3 {
+		let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "wrong", "username": "password"})).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, "Invalid username or password.");
+	}
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "password", "username": "password"})).to_request();
+	let response = test::call_service(&application, request).await;
+	assert_eq!(response.status(), StatusCode::FORBIDDEN);
+	let response: BaseResponse = test::read_body_json(response).await;
+	assert_eq!(response.response, "Account is locked. Please try again later.");
+
+	let mut user: User = find_one_by_username(&database, "password").await.unwrap();
+	user.password.locked = Utc::now() - Duration::seconds(1);
+	user.password.recovery = digest(b"recovery");
+	user.password.recovery_expired = Utc::now() + Duration::hours(1);
+	replace_one(&database, &user, None).await.unwrap();
+
+	for (recovery, password, message) in [("invalid", "new-password", "Invalid or expired recovery link."), ("recovery", "short", "Password must be at least 8 characters.")] {
+		let request = test::TestRequest::post().uri("/user/password/reset").insert_header(("pld-key", "user")).set_json(json!({"password": password, "recovery": recovery, "username": "password"})).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, message);
+	}
+
+	let (first, second): (BaseResponse, BaseResponse) = tokio::join!(
+		test::call_and_read_body_json(&application, test::TestRequest::post().uri("/user/password/reset").insert_header(("pld-key", "user")).set_json(json!({"password": "new-password", "recovery": "recovery", "username": "password"})).to_request()),
+		test::call_and_read_body_json(&application, test::TestRequest::post().uri("/user/password/reset").insert_header(("pld-key", "user")).set_json(json!({"password": "new-password", "recovery": "recovery", "username": "password"})).to_request())
+	);
+	assert_eq!([first.result, second.result].iter().filter(|result| **result).count(), 1);
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "new-password", "username": "password"})).to_request();
+	let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(login.result);
+
+	for (current, message) in [("wrong", "Invalid current password."), ("new-password", "Password has been changed successfully.")] {
+		let request = test::TestRequest::post().uri("/user/password/change").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "current": current, "password": "changed-password"})).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, message);
+	}
+
+	let (first, second, third) = tokio::join!(
+		update_password_attempt(&database, &user.id),
+		update_password_attempt(&database, &user.id),
+		update_password_attempt(&database, &user.id)
+	);
+	let mut attempts: Vec<u32> = [first, second, third].into_iter().map(|user| user.unwrap().unwrap().password.attempt).collect();
+	attempts.sort();
+	assert_eq!(attempts, vec![1, 2, 3]);
+
+	database.drop().await.unwrap();
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn totp_is_required_after_activation() {
+	common::initialize_client("user");
+
+	let database: Database = common::initialize_database("user").await;
+	create_index(&database).await;
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(AppConfig::instance())).app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "user")).set_json(json!({"email": "totp@example.com", "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": "totp"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "password", "username": "totp"})).to_request();
+	let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(login.result && !login.totp);
+
+	let request = test::TestRequest::post().uri("/user/totp/enroll").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication})).to_request();
+	let enroll: UserTotpEnrollResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(enroll.uri.starts_with("otpauth://totp/"));
+
+	let request = test::TestRequest::post().uri("/user/totp/activate").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "code": generate_code(&enroll.secret, get_step())})).to_request();
+	let activate: UserTotpVerifyResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(activate.result, "{}", activate.response);
+	assert_eq!(activate.recovery.len(), 10);
+
+	let user: User = find_one_by_username(&database, "totp").await.unwrap();
+	assert!(!user.totp.recovery.contains(&activate.recovery[0]));
+
+	for (code, status, result, message) in [
+		(None, StatusCode::OK, false, "Two-factor authentication code required."),
+		(Some("000000".to_string()), StatusCode::UNAUTHORIZED, false, "Invalid two-factor authentication code."),
+		(Some(activate.recovery[0].clone()), StatusCode::OK, true, "Login successful."),
+		(Some(activate.recovery[0].clone()), StatusCode::UNAUTHORIZED, false, "Invalid two-factor authentication code."),
+	] {
+		let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"code": code, "password": "password", "username": "totp"})).to_request();
+		let response = test::call_service(&application, request).await;
+		assert_eq!(response.status(), status);
+		let response: BaseResponse = test::read_body_json(response).await;
+		assert_eq!((response.result, response.response.as_str()), (result, message));
+	}
+
+	let recovery: String = digest(activate.recovery[1].as_bytes());
+	let (first, second) = tokio::join!(update_totp_recovery(&database, &user.id, &recovery), update_totp_recovery(&database, &user.id, &recovery));
+	assert_eq!(first.unwrap().modified_count + second.unwrap().modified_count, 1);
+
+	let step: i64 = user.totp.last + 1_000_000;
+	let (first, second) = tokio::join!(update_totp_last(&database, &user.id, step), update_totp_last(&database, &user.id, step));
+	assert_eq!(first.unwrap().modified_count + second.unwrap().modified_count, 1);
+
+	database.drop().await.unwrap();
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn administrator_manages_users() {
+	common::initialize_client("user");
+
+	let database: Database = common::initialize_database("user").await;
+	create_index(&database).await;
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(AppConfig::instance())).app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "user")).set_json(json!({"email": "admin@example.com", "name": {"first": "", "last": "", "middle": ""}, "password": "password", "username": "admin"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let mut admin: User = find_one_by_username(&database, "admin").await.unwrap();
+	admin._type = UserType::Administrator;
+	replace_one(&database, &admin, None).await.unwrap();
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "password", "username": "admin"})).to_request();
+	let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+
+	for (body, message) in [
+		(json!({"contact": {"email": "managed@example.com"}, "password": "password", "type": "Owner", "username": "managed"}), "Access denied."),
+		(json!({"contact": {"email": "invalid"}, "password": "password", "username": "managed"}), "Invalid email address."),
+		(json!({"contact": {"email": "managed@example.com"}, "password": "password", "username": "managed"}), "User has been created successfully."),
+	] {
+		let mut body: Value = body;
+		body["authentication"] = json!(login.authentication);
+
+		let request = test::TestRequest::post().uri("/user/create").insert_header(("pld-key", "user")).set_json(&body).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, message);
+	}
+
+	let request = test::TestRequest::post().uri("/user/list").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "search": "MANAGED", "size": 1})).to_request();
+	let list: UserListResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!((list.total, list.data.len(), list.data[0].username.as_str()), (1, 1, "managed"));
+
+	let request = test::TestRequest::post().uri("/user/update").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "contact": {"email": "Updated@Example.com"}, "name": {"first": "Managed", "last": "", "middle": ""}, "username": "managed"})).to_request();
+	let update: UserResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(update.data.unwrap().contact.email, "updated@example.com");
+	assert_eq!(database.collection::<Document>("user_log_data").count_documents(doc! {}).await.unwrap(), 1);
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "password", "username": "managed"})).to_request();
+	let managed: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+
+	let request = test::TestRequest::post().uri("/user/suspend").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "username": "managed"})).to_request();
+	let suspend: UserResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(suspend.data.unwrap().status, Status::Inactive);
+
+	let request = test::TestRequest::post().uri("/user/me").insert_header(("pld-key", "user")).set_json(json!({"authentication": managed.authentication})).to_request();
+	assert_eq!(test::call_service(&application, request).await.status(), StatusCode::UNAUTHORIZED);
+
+	for (username, message) in [("admin", "Access denied."), ("managed", "Two-factor authentication has been reset successfully.")] {
+		let request = test::TestRequest::post().uri("/user/totp/reset").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "username": username})).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, message);
+	}
+	assert_eq!(database.collection::<Document>("user_log_data").count_documents(doc! {}).await.unwrap(), 3);
+
+	for (username, message) in [("admin", "Access denied."), ("managed", "User has been deleted successfully."), ("managed", "User not found.")] {
+		let request = test::TestRequest::post().uri("/user/delete").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "username": username})).to_request();
+		let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, message);
+	}
+
+	database.drop().await.unwrap();
+}
+
+#[actix_web::test]
+#[ignore = "requires MongoDB"]
+async fn profile_is_updated_and_archived() {
+	common::initialize_client("user");
+
+	let database: Database = common::initialize_database("user").await;
+	create_index(&database).await;
+	let application = test::init_service(
+		App::new().app_data(web::Data::new(AppConfig::instance())).app_data(web::Data::new(database.clone())).service(
+			web::scope("/user")
+				.wrap(Authentication::new(Access::Public))
+				.configure(routes::config),
+		),
+	)
+	.await;
+
+	let request = test::TestRequest::post().uri("/user/register").insert_header(("pld-key", "user")).set_json(json!({"email": "profile@example.com", "name": {"first": "Profile", "last": "", "middle": ""}, "password": "password", "username": "profile"})).to_request();
+	let response: BaseResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/login").insert_header(("pld-key", "user")).set_json(json!({"password": "password", "username": "profile"})).to_request();
+	let login: UserLoginResponse = test::call_and_read_body_json(&application, request).await;
+
+	let request = test::TestRequest::post().uri("/user/me/update").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "address": {"city": "", "country": "Atlantis", "district": "", "state": "", "street": "", "zip": ""}})).to_request();
+	let response: UserResponse = test::call_and_read_body_json(&application, request).await;
+	assert_eq!(response.response, "Invalid country.");
+
+	let request = test::TestRequest::post().uri("/user/me/update").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "address": {"city": "Jakarta", "country": "Indonesia", "district": "", "state": "", "street": "", "zip": ""}, "layout": {"sidebar": "Collapsed", "version": "Dark"}})).to_request();
+	let response: UserResponse = test::call_and_read_body_json(&application, request).await;
+	assert!(response.result, "{}", response.response);
+
+	let request = test::TestRequest::post().uri("/user/me").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication})).to_request();
+	let profile: UserResponse = test::call_and_read_body_json(&application, request).await;
+	let data: UserData = profile.data.unwrap();
+	assert_eq!((data.address.city.as_str(), data.layout.version, data.contact.email.as_str()), ("Jakarta", Version::Dark, "profile@example.com"));
+
+	let archived: Document = database.collection::<Document>("user_log_data").find_one(doc! {"username": "profile"}).await.unwrap().unwrap();
+	assert_eq!(archived.get_document("layout").unwrap().get_str("version").unwrap(), "Light");
+
+	for (current, message) in [(None, "Current password is required to change email."), (Some("wrong"), "Invalid current password."), (Some("password"), "Profile has been updated successfully.")] {
+		let request = test::TestRequest::post().uri("/user/me/update").insert_header(("pld-key", "user")).set_json(json!({"authentication": login.authentication, "contact": {"discord": "", "email": "changed@example.com", "phone": "", "skype": "", "slack": "", "telegram": "", "wechat": "", "whatsapp": ""}, "current": current})).to_request();
+		let response: UserResponse = test::call_and_read_body_json(&application, request).await;
+		assert_eq!(response.response, message);
+	}
+
+	database.drop().await.unwrap();
+}